@@ -1,13 +1,58 @@
-use std::{env, fmt, time::Duration};
+use std::{
+    collections::HashMap,
+    env, fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use reqwest::{ClientBuilder, RequestBuilder, header};
-use serde::{Deserialize, Serialize};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use tokio::time;
 
 use crate::{
     NotionAPI, NotionAuthedAPI,
     types::{ClientResult, ErrorResponse, Method, NotionResponse},
 };
 
+/// 3 requests per second per integration (README - Request Limits)
+const REQUEST_INTERVAL: Duration = Duration::from_millis(334);
+
+/// Spaces out the requests of each token, shared by every clone of a `NotionAPI`
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn acquire(&self, token: &str) {
+        if self.interval.is_zero() {
+            return;
+        }
+
+        let at = {
+            let mut next = self.next.lock().unwrap();
+            let now = Instant::now();
+            // tokens that have been idle for a full interval need no entry
+            next.retain(|_, at| *at > now);
+
+            let slot = next.entry(token.into()).or_insert(now);
+            let at = *slot;
+            *slot = at + self.interval;
+            at
+        };
+
+        time::sleep_until(at.into()).await;
+    }
+}
+
 impl NotionAPI {
     pub fn from_env() -> Self {
         let version = env::var("NOTION_API_VERSION")
@@ -29,9 +74,17 @@ impl NotionAPI {
                 .build()
                 .expect("Failed to build HTTP client"),
             base_url: "https://api.notion.com/v1".to_string(),
+            limiter: Arc::new(RateLimiter::new(REQUEST_INTERVAL)),
         }
     }
 
+    /// Minimum time between two requests of the same token, `Duration::ZERO` turns
+    /// throttling off. Clones made afterwards share the new limiter.
+    pub fn with_request_interval(mut self, interval: Duration) -> Self {
+        self.limiter = Arc::new(RateLimiter::new(interval));
+        self
+    }
+
     pub fn authed<'a>(&'a self, token: &'a str) -> NotionAuthedAPI<'a> {
         NotionAuthedAPI { api: self, token }
     }
@@ -51,12 +104,14 @@ impl NotionAPI {
 }
 
 impl<'a> NotionAuthedAPI<'a> {
-    pub async fn send<T: Serialize, U: for<'d> Deserialize<'d> + fmt::Debug>(
+    pub async fn send<T: Serialize, U: DeserializeOwned + fmt::Debug>(
         &self,
         endpoint: &str,
         method: Method,
         body: T,
     ) -> ClientResult<NotionResponse<U>> {
+        self.api.limiter.acquire(self.token).await;
+
         let res = self
             .api
             .build_request(method, endpoint)
@@ -66,14 +121,40 @@ impl<'a> NotionAuthedAPI<'a> {
             .await?;
 
         if res.status().is_success() == false {
-            let err_response = res.json::<ErrorResponse>().await.unwrap();
+            let err_response = res.json::<ErrorResponse>().await?;
             // TODO? log.error
             println!("{:?}", err_response);
             let err = err_response.into();
             return Err(err);
         }
 
-        let res_body = res.json::<NotionResponse<U>>().await.unwrap();
-        Ok(res_body)
+        NotionResponse::from_value(res.json::<Value>().await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::RateLimiter;
+
+    #[tokio::test]
+    async fn test_rate_limiter_per_token() {
+        let limiter = RateLimiter::new(Duration::from_millis(100));
+        let start = Instant::now();
+
+        limiter.acquire("a").await;
+        limiter.acquire("b").await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        limiter.acquire("a").await;
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        let unlimited = RateLimiter::new(Duration::ZERO);
+        let start = Instant::now();
+        for _ in 0..10 {
+            unlimited.acquire("a").await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
     }
 }
@@ -1,6 +1,8 @@
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::types::encode_property_id;
+
 #[derive(Serialize)]
 pub struct QueryBody {
     filter: Value,
@@ -16,10 +18,11 @@ impl QueryBody {
 pub struct PropertyFilters;
 
 impl PropertyFilters {
+    /// * `values` - decoded property IDs
     pub fn as_query(values: Vec<&str>) -> String {
         values
             .iter()
-            .map(|v| format!("filter_properties[]={}", v))
+            .map(|v| format!("filter_properties[]={}", encode_property_id(v)))
             .collect::<Vec<String>>()
             .join("&")
     }
@@ -5,14 +5,19 @@ use serde_json::Value;
 use crate::{
     data_source::types::{DataSourceParent, Property},
     database::types::DatabaseParent,
+    errors::ClientError,
+    ids::{DataSourceId, PageId},
     page::types::Page,
-    types::{Cover, Icon, Property as PageProperty, PropertyValue, Title},
+    types::{
+        ClientResult, Cover, Icon, Property as PageProperty, PropertyRef, PropertyValue,
+        decode_property_id, rich_text::RichText,
+    },
 };
 
 #[derive(Deserialize, Debug)]
 pub struct GetDataSourceResponse {
-    id: String,
-    title: Vec<Title>,
+    id: DataSourceId,
+    title: Vec<RichText>,
     archived: bool,
     is_inline: bool,
 
@@ -30,13 +35,83 @@ pub struct GetDataSourceResponse {
 }
 
 impl GetDataSourceResponse {
-    pub fn get_properties(&self) -> Vec<Property> {
+    pub fn get_properties(&self) -> ClientResult<Vec<Property>> {
         self.properties
             .as_object()
-            .unwrap()
-            .iter()
-            .map(|(_, v)| v.into())
-            .collect::<Vec<Property>>()
+            .into_iter()
+            .flatten()
+            .map(|(_, v)| v.try_into())
+            .collect::<ClientResult<Vec<Property>>>()
+    }
+
+    pub fn find_property(&self, property: &PropertyRef) -> ClientResult<Option<Property>> {
+        Ok(self.get_properties()?.into_iter().find(|p| p.is(property)))
+    }
+
+    /// Decoded ID of a property given by name or ID
+    pub fn resolve_property_id(&self, property: &PropertyRef) -> ClientResult<String> {
+        match self.find_property(property)? {
+            Some(p) => Ok(p.get_id().into()),
+            None => Err(ClientError::PropertyNotFoundError(property.as_str().into())),
+        }
+    }
+
+    /// Rewrites every `"property"` of a (compound) filter to the property ID.
+    /// Values are matched by name first, then by ID.
+    pub fn resolve_filter(&self, filter: Value) -> ClientResult<Value> {
+        let properties = self.get_properties()?;
+
+        resolve_filter_properties(filter, &|key| {
+            properties
+                .iter()
+                .find(|p| p.get_name() == key)
+                .or_else(|| properties.iter().find(|p| p.get_id() == key))
+                .map(|p| p.get_id().to_string())
+                .ok_or_else(|| ClientError::PropertyNotFoundError(key.into()))
+        })
+    }
+}
+
+/// Whether a (compound) filter refers to a property by something other than its ID
+pub(crate) fn has_filter_property_names(filter: &Value) -> bool {
+    match filter {
+        Value::Object(map) => map.iter().any(|(k, v)| match (k.as_str(), v) {
+            ("property", Value::String(key)) => !looks_like_property_id(key),
+            _ => has_filter_property_names(v),
+        }),
+        Value::Array(items) => items.iter().any(has_filter_property_names),
+        _ => false,
+    }
+}
+
+/// Property IDs are `title` or up to 4 ASCII characters, URL-encoded or not
+/// (`Upwn`, `%3CO%7Cd`). Short ASCII names look the same, but the API accepts
+/// names too, so those are sent unresolved.
+fn looks_like_property_id(key: &str) -> bool {
+    let id = decode_property_id(key);
+
+    key == "title" || (id.is_ascii() && !id.contains(' ') && (1..=4).contains(&id.len()))
+}
+
+fn resolve_filter_properties(
+    filter: Value,
+    resolve: &impl Fn(&str) -> ClientResult<String>,
+) -> ClientResult<Value> {
+    match filter {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(k, v)| match (k.as_str(), &v) {
+                ("property", Value::String(key)) => Ok((k, Value::String(resolve(key)?))),
+                _ => Ok((k, resolve_filter_properties(v, resolve)?)),
+            })
+            .collect::<ClientResult<_>>()
+            .map(Value::Object),
+        Value::Array(items) => items
+            .into_iter()
+            .map(|v| resolve_filter_properties(v, resolve))
+            .collect::<ClientResult<_>>()
+            .map(Value::Array),
+        value => Ok(value),
     }
 }
 
@@ -48,27 +123,35 @@ pub struct QueryPageListResponse {
 }
 
 impl QueryPageListResponse {
-    pub fn get_pages(&self) -> Vec<PageInfo> {
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    pub fn get_next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+
+    pub fn get_pages(&self) -> ClientResult<Vec<PageInfo>> {
         self.results
             .iter()
-            .map(|p| PageInfo::new(p.get_id(), p.get_properties()))
-            .collect::<Vec<PageInfo>>()
+            .map(|p| Ok(PageInfo::new(p.get_id(), p.get_properties()?)))
+            .collect::<ClientResult<Vec<PageInfo>>>()
     }
 }
 
 pub struct PageInfo {
-    id: String,
+    id: PageId,
     properties: Vec<PageProperty>,
 }
 
 impl PageInfo {
-    fn new(id: &str, properties: Vec<PageProperty>) -> Self {
+    fn new(id: &PageId, properties: Vec<PageProperty>) -> Self {
         Self {
-            id: id.into(),
+            id: id.clone(),
             properties,
         }
     }
-    pub fn get_id(&self) -> &str {
+    pub fn get_id(&self) -> &PageId {
         &self.id
     }
 
@@ -76,7 +159,10 @@ impl PageInfo {
         &self.properties
     }
 
-    // TODO: Vec<> / add find by name
+    pub fn get_property(&self, property: &PropertyRef) -> Option<&PageProperty> {
+        self.properties.iter().find(|p| p.is(property))
+    }
+
     pub fn find_property(&self, property_value: &PropertyValue) -> Option<String> {
         match self
             .properties
@@ -88,3 +174,29 @@ impl PageInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::has_filter_property_names;
+
+    #[test]
+    fn test_filter_property_names() {
+        let by_id = json!({"and": [
+            {"property": "title", "title": {"is_not_empty": true}},
+            {"property": "%3CO%7Cd", "relation": {"is_empty": true}},
+            {"property": "^TuB", "checkbox": {"equals": true}}
+        ]});
+        assert!(!has_filter_property_names(&by_id));
+
+        let by_name = json!({"or": [
+            {"property": "Upwn", "relation": {"is_empty": true}},
+            {"property": "날짜", "date": {"after": "2025-12-15"}}
+        ]});
+        assert!(has_filter_property_names(&by_name));
+        assert!(has_filter_property_names(
+            &json!({"property": "Due date", "date": {"is_empty": true}})
+        ));
+    }
+}
@@ -3,10 +3,15 @@ use serde_json::{Value, json};
 use crate::{
     NotionAuthedAPI,
     data_source::dto::{
-        request::{PropertyFilters, QueryBody},
-        response::{GetDataSourceResponse, QueryPageListResponse},
+        request::{PropertyFilters, QueryBody, Sort},
+        response::{GetDataSourceResponse, QueryPageListResponse, has_filter_property_names},
     },
-    types::{ClientResult, Method, NotionResponse},
+    data_source::types::PropertyInfo,
+    errors::ClientError,
+    ids::DataSourceId,
+    page::types::Page,
+    pagination::Paginator,
+    types::{ClientResult, Method, NotionResponse, PropertyRef, unique_id::UniqueIdValue},
 };
 
 pub mod dto;
@@ -17,22 +22,35 @@ pub trait DataSourceClient: Send + Sync {
     // update
     fn get_data_source(
         &self,
-        data_source_id: &str,
+        data_source_id: &DataSourceId,
     ) -> impl Future<Output = ClientResult<NotionResponse<GetDataSourceResponse>>>;
     fn query_pages(
         &self,
-        data_source_id: &str,
-        properties: Vec<&str>,
+        data_source_id: &DataSourceId,
+        properties: Vec<PropertyRef>,
         filters: Value,
-        sorts: Vec<(&str, &str)>,
+        sorts: Vec<(PropertyRef, &str)>,
     ) -> impl Future<Output = ClientResult<NotionResponse<QueryPageListResponse>>>;
+    fn query_paginator<'s>(
+        &'s self,
+        data_source_id: &DataSourceId,
+        properties: Vec<PropertyRef>,
+        filters: Value,
+        sorts: Vec<(PropertyRef, &str)>,
+    ) -> impl Future<Output = ClientResult<Paginator<'s, Page>>>;
+    /// Page by the ID Notion shows in its unique_id property, e.g. `TASK-42`
+    fn find_page_by_unique_id(
+        &self,
+        data_source_id: &DataSourceId,
+        unique_id: &str,
+    ) -> impl Future<Output = ClientResult<Option<Page>>>;
     // get_templates
 }
 
 impl<'a> DataSourceClient for NotionAuthedAPI<'a> {
     async fn get_data_source(
         &self,
-        data_source_id: &str,
+        data_source_id: &DataSourceId,
     ) -> ClientResult<NotionResponse<GetDataSourceResponse>> {
         let endpoint = format!("data_sources/{}", data_source_id);
 
@@ -43,21 +61,20 @@ impl<'a> DataSourceClient for NotionAuthedAPI<'a> {
         Ok(response)
     }
 
-    /// * `sorts` - Sorting property name and direction (asc, desc)
+    /// * `properties` - `filter_properties`, only these properties are returned
+    /// * `sorts` - Sorting property and direction (asc, desc)
+    ///
+    /// Property names are resolved to IDs against the data source schema
     async fn query_pages(
         &self,
-        data_source_id: &str,
-        properties: Vec<&str>,
+        data_source_id: &DataSourceId,
+        properties: Vec<PropertyRef>,
         filters: Value,
-        sorts: Vec<(&str, &str)>,
+        sorts: Vec<(PropertyRef, &str)>,
     ) -> ClientResult<NotionResponse<QueryPageListResponse>> {
-        let endpoint = format!(
-            "data_sources/{}/query?{}",
-            data_source_id,
-            PropertyFilters::as_query(properties)
-        );
-
-        let body = QueryBody::new(filters, sorts.iter().map(|s| s.into()).collect());
+        let (endpoint, body) = self
+            .build_query(data_source_id, properties, filters, sorts)
+            .await?;
 
         let response = self
             .send::<QueryBody, QueryPageListResponse>(&endpoint, Method::Post, body)
@@ -65,4 +82,114 @@ impl<'a> DataSourceClient for NotionAuthedAPI<'a> {
 
         Ok(response)
     }
+
+    /// Same query as `query_pages`, following `next_cursor` until every page is read
+    async fn query_paginator<'s>(
+        &'s self,
+        data_source_id: &DataSourceId,
+        properties: Vec<PropertyRef>,
+        filters: Value,
+        sorts: Vec<(PropertyRef, &str)>,
+    ) -> ClientResult<Paginator<'s, Page>> {
+        let (endpoint, body) = self
+            .build_query(data_source_id, properties, filters, sorts)
+            .await?;
+
+        Ok(self.paginate(&endpoint, Method::Post, json!(body)))
+    }
+
+    /// `None` when no page has the number or the prefix belongs to another data source
+    async fn find_page_by_unique_id(
+        &self,
+        data_source_id: &DataSourceId,
+        unique_id: &str,
+    ) -> ClientResult<Option<Page>> {
+        let unique_id = UniqueIdValue::parse(unique_id)?;
+
+        let response = self.get_data_source(data_source_id).await?;
+        let (property_id, prefix) = response
+            .get_data()?
+            .get_properties()?
+            .into_iter()
+            .find_map(|p| match p.get_property_info() {
+                PropertyInfo::UniqueId { prefix } => Some((p.get_id().to_string(), prefix.clone())),
+                _ => None,
+            })
+            .ok_or_else(|| ClientError::PropertyNotFoundError("unique_id".into()))?;
+
+        let prefix_matches = match (unique_id.get_prefix(), prefix.as_deref()) {
+            (Some(given), Some(prefix)) => given.eq_ignore_ascii_case(prefix),
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        if !prefix_matches {
+            return Ok(None);
+        }
+
+        let filter = json!({
+            "property": property_id,
+            "unique_id": {"equals": unique_id.get_number()},
+        });
+        let endpoint = format!("data_sources/{}/query", data_source_id);
+        let pages = self
+            .paginate::<Page>(
+                &endpoint,
+                Method::Post,
+                json!(QueryBody::new(filter, vec![])),
+            )
+            .page_size(1)
+            .max_items(1)
+            .collect_all()
+            .await?;
+
+        Ok(pages.into_iter().next())
+    }
+}
+
+impl<'a> NotionAuthedAPI<'a> {
+    /// Fetches the schema only when property names need resolving
+    async fn build_query(
+        &self,
+        data_source_id: &DataSourceId,
+        properties: Vec<PropertyRef>,
+        filters: Value,
+        sorts: Vec<(PropertyRef, &str)>,
+    ) -> ClientResult<(String, QueryBody)> {
+        let is_name = |p: &PropertyRef| matches!(p, PropertyRef::Name(_));
+        let needs_schema = properties.iter().any(is_name)
+            || sorts.iter().any(|(p, _)| is_name(p))
+            || has_filter_property_names(&filters);
+
+        let (property_ids, filters, sorts) = if needs_schema {
+            let response = self.get_data_source(data_source_id).await?;
+            let schema = response.get_data()?;
+
+            let property_ids = properties
+                .iter()
+                .map(|p| schema.resolve_property_id(p))
+                .collect::<ClientResult<Vec<String>>>()?;
+            let sorts = sorts
+                .iter()
+                .map(|(p, d)| Ok(Sort::new(&schema.resolve_property_id(p)?, (*d).into())))
+                .collect::<ClientResult<Vec<Sort>>>()?;
+
+            (property_ids, schema.resolve_filter(filters)?, sorts)
+        } else {
+            let property_ids = properties.iter().map(|p| p.as_str().to_string()).collect();
+            let sorts = sorts
+                .iter()
+                .map(|(p, d)| Sort::new(p.as_str(), (*d).into()))
+                .collect();
+
+            (property_ids, filters, sorts)
+        };
+
+        let endpoint = format!(
+            "data_sources/{}/query?{}",
+            data_source_id,
+            PropertyFilters::as_query(property_ids.iter().map(|p| p.as_str()).collect())
+        );
+
+        Ok((endpoint, QueryBody::new(filters, sorts)))
+    }
 }
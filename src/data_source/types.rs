@@ -1,11 +1,22 @@
 use serde::Deserialize;
 use serde_json::Value;
 
+use crate::{
+    errors::ClientError,
+    ids::DatabaseId,
+    types::{
+        ClientResult, PropertyRef, decode_property_id,
+        display::NumberFormat,
+        parse_payload,
+        select::{SelectOption, StatusGroup},
+    },
+};
+
 #[derive(Deserialize, Debug)]
 pub struct DataSourceParent {
     #[serde(rename(deserialize = "type"))]
     parent_type: String,
-    database_id: String,
+    database_id: DatabaseId,
 }
 
 #[derive(Debug)]
@@ -15,24 +26,44 @@ pub struct Property {
     property_info: PropertyInfo,
 }
 
-impl From<&Value> for Property {
-    fn from(value: &Value) -> Self {
-        let property_info: PropertyInfo = value.into();
-
-        Property {
-            id: value.get("id").unwrap().to_string(),
-            name: value.get("name").unwrap().as_str().unwrap().into(),
+impl TryFrom<&Value> for Property {
+    type Error = ClientError;
+
+    fn try_from(value: &Value) -> ClientResult<Self> {
+        // a configuration this crate can't read shouldn't fail the whole schema
+        let property_info = PropertyInfo::try_from(value).unwrap_or_else(|_| {
+            let type_name = value["type"].as_str().unwrap_or_default();
+            PropertyInfo::Unknown {
+                type_name: type_name.into(),
+                raw: value.get(type_name).cloned().unwrap_or_default(),
+            }
+        });
+        let name = value["name"]
+            .as_str()
+            .ok_or_else(|| ClientError::InvalidPropertyError("missing name".into()))?;
+
+        Ok(Property {
+            id: decode_property_id(value["id"].as_str().unwrap_or_default()),
+            name: name.into(),
             // dsecription: null
             property_info,
-        }
+        })
     }
 }
 
 impl Property {
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
 
+    pub fn is(&self, property: &PropertyRef) -> bool {
+        property.matches(&self.id, &self.name)
+    }
+
     pub fn get_property_info(&self) -> &PropertyInfo {
         &self.property_info
     }
@@ -40,21 +71,34 @@ impl Property {
 
 #[derive(Debug)]
 pub enum PropertyInfo {
-    ID,
+    /// `prefix` of the generated IDs (`TASK` in `TASK-42`)
+    UniqueId {
+        prefix: Option<String>,
+    },
     Verification,
     Title,
     RichText,
 
-    Number,
+    Number {
+        format: NumberFormat,
+    },
     Checkbox,
     Date,
     Timestamp,
 
-    Status,
-    Select,
-    MultiSelect,
+    Status {
+        options: Vec<SelectOption>,
+        groups: Vec<StatusGroup>,
+    },
+    Select {
+        options: Vec<SelectOption>,
+    },
+    MultiSelect {
+        options: Vec<SelectOption>,
+    },
 
     Url,
+    Email,
     Place,
     People,
     PhoneNumber,
@@ -65,12 +109,45 @@ pub enum PropertyInfo {
     Rollup,
 
     CreatedTime,
+    CreatedBy,
     LastEditedTime,
+    LastEditedBy,
+
+    /// Type added to Notion after this crate, with its raw configuration
+    Unknown {
+        type_name: String,
+        raw: Value,
+    },
 }
 
-impl From<&Value> for PropertyInfo {
-    fn from(value: &Value) -> Self {
-        let type_str = value.get("type").unwrap().as_str().unwrap();
+impl PropertyInfo {
+    /// Options of a select, multi_select or status property
+    pub fn get_options(&self) -> &[SelectOption] {
+        match self {
+            PropertyInfo::Select { options }
+            | PropertyInfo::MultiSelect { options }
+            | PropertyInfo::Status { options, .. } => options,
+            _ => &[],
+        }
+    }
+
+    /// Option by ID or, failing that, by name
+    pub fn find_option(&self, id_or_name: &str) -> Option<&SelectOption> {
+        let options = self.get_options();
+        options
+            .iter()
+            .find(|o| o.get_id() == Some(id_or_name))
+            .or_else(|| options.iter().find(|o| o.get_name() == id_or_name))
+    }
+}
+
+impl TryFrom<&Value> for PropertyInfo {
+    type Error = ClientError;
+
+    fn try_from(value: &Value) -> ClientResult<Self> {
+        let type_str = value["type"]
+            .as_str()
+            .ok_or_else(|| ClientError::InvalidPropertyError("missing type".into()))?;
         // property_value = value.get(type_str)
 
         // {"type":"select","select":{"options":[{"color":"green","description":null,"id":"816b184a-9dd3-4266-9e2e-16f5cae1c137","name":"작업물"},{"color":"yellow","description":null,"id":"9cf32baa-6966-4200-b955-7d455ac3dea2","name":"레퍼런스"},{"color":"purple","description":null,"id":"bb9c2298-7573-43c1-b78f-d6e6550c2b5c","name":"나중에 보기"}]}}
@@ -95,20 +172,33 @@ impl From<&Value> for PropertyInfo {
         // {"type":"date","date":{}}
         // {"type":"last_edited_time","last_edited_time":{}}
 
-        match type_str {
+        let config = &value[type_str];
+        let property_info = match type_str {
             "title" => PropertyInfo::Title,
             "date" => PropertyInfo::Date,
             "checkbox" => PropertyInfo::Checkbox,
+            "email" => PropertyInfo::Email,
             "files" => PropertyInfo::Files,
-            "id" => PropertyInfo::ID,
-            "multi_select" => PropertyInfo::MultiSelect,
-            "number" => PropertyInfo::Number,
+            "unique_id" => PropertyInfo::UniqueId {
+                prefix: config["prefix"].as_str().map(|p| p.into()),
+            },
+            "multi_select" => PropertyInfo::MultiSelect {
+                options: parse_payload(type_str, &config["options"])?,
+            },
+            "number" => PropertyInfo::Number {
+                format: NumberFormat::parse(config["format"].as_str().unwrap_or_default()),
+            },
             "people" => PropertyInfo::People,
             "phone_number" => PropertyInfo::PhoneNumber,
             "relation" => PropertyInfo::Relation,
             "rich_text" => PropertyInfo::RichText,
-            "select" => PropertyInfo::Select,
-            "status" => PropertyInfo::Status,
+            "select" => PropertyInfo::Select {
+                options: parse_payload(type_str, &config["options"])?,
+            },
+            "status" => PropertyInfo::Status {
+                options: parse_payload(type_str, &config["options"])?,
+                groups: parse_payload(type_str, &config["groups"])?,
+            },
             "timestamp" => PropertyInfo::Timestamp,
             "verification" => PropertyInfo::Verification,
             "place" => PropertyInfo::Place,
@@ -116,28 +206,36 @@ impl From<&Value> for PropertyInfo {
             "formula" => PropertyInfo::Formula,
             "rollup" => PropertyInfo::Rollup,
             "created_time" => PropertyInfo::CreatedTime,
+            "created_by" => PropertyInfo::CreatedBy,
             "last_edited_time" => PropertyInfo::LastEditedTime,
-            _ => panic!("Invalid Notion Property Type"),
-        }
+            "last_edited_by" => PropertyInfo::LastEditedBy,
+            _ => PropertyInfo::Unknown {
+                type_name: type_str.into(),
+                raw: value.get(type_str).cloned().unwrap_or_default(),
+            },
+        };
+
+        Ok(property_info)
     }
 }
 
 impl ToString for PropertyInfo {
     fn to_string(&self) -> String {
         match self {
-            PropertyInfo::ID => "id",
+            PropertyInfo::UniqueId { .. } => "unique_id",
             PropertyInfo::Title => "title",
             PropertyInfo::Date => "date",
             PropertyInfo::Checkbox => "checkbox",
+            PropertyInfo::Email => "email",
             PropertyInfo::Files => "files",
-            PropertyInfo::MultiSelect => "multi_select",
-            PropertyInfo::Number => "number",
+            PropertyInfo::MultiSelect { .. } => "multi_select",
+            PropertyInfo::Number { .. } => "number",
             PropertyInfo::People => "people",
             PropertyInfo::PhoneNumber => "phone_number",
             PropertyInfo::Relation => "relation",
             PropertyInfo::RichText => "rich_text",
-            PropertyInfo::Select => "select",
-            PropertyInfo::Status => "status",
+            PropertyInfo::Select { .. } => "select",
+            PropertyInfo::Status { .. } => "status",
             PropertyInfo::Timestamp => "timestamp",
             PropertyInfo::Verification => "verification",
             PropertyInfo::Place => "place",
@@ -145,7 +243,10 @@ impl ToString for PropertyInfo {
             PropertyInfo::Formula => "formula",
             PropertyInfo::Rollup => "rollup",
             PropertyInfo::CreatedTime => "created_time",
+            PropertyInfo::CreatedBy => "created_by",
             PropertyInfo::LastEditedTime => "last_edited_time",
+            PropertyInfo::LastEditedBy => "last_edited_by",
+            PropertyInfo::Unknown { type_name, .. } => type_name,
         }
         .into()
     }
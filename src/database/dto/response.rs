@@ -3,13 +3,14 @@ use serde::Deserialize;
 
 use crate::{
     database::types::{DataSource, DatabaseParent},
-    types::{Cover, Icon, Title},
+    ids::DatabaseId,
+    types::{Cover, Icon, rich_text::RichText},
 };
 
 #[derive(Deserialize, Debug)]
 pub struct GetDatabaseResponse {
-    id: String,
-    title: Vec<Title>,
+    id: DatabaseId,
+    title: Vec<RichText>,
     parent: DatabaseParent,
     is_inline: bool,
     in_trash: bool,
@@ -19,7 +20,7 @@ pub struct GetDatabaseResponse {
     public_url: Option<String>,
 
     data_sources: Vec<DataSource>,
-    description: Vec<String>,
+    description: Vec<RichText>,
 
     url: String,
     created_time: DateTime<Utc>,
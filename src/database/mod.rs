@@ -6,6 +6,7 @@ use serde_json::{Value, json};
 use crate::{
     NotionAuthedAPI,
     database::dto::response::GetDatabaseResponse,
+    ids::DatabaseId,
     types::{ClientResult, Method, NotionResponse},
 };
 
@@ -14,14 +15,14 @@ pub trait DatabaseClient: Send + Sync {
     // update
     fn get_database(
         &self,
-        database_id: &str,
+        database_id: &DatabaseId,
     ) -> impl Future<Output = ClientResult<NotionResponse<GetDatabaseResponse>>>;
 }
 
 impl<'a> DatabaseClient for NotionAuthedAPI<'a> {
     async fn get_database(
         &self,
-        database_id: &str,
+        database_id: &DatabaseId,
     ) -> ClientResult<NotionResponse<GetDatabaseResponse>> {
         let endpoint = format!("databases/{}", database_id);
 
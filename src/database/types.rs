@@ -1,8 +1,10 @@
 use serde::Deserialize;
 
+use crate::ids::{DataSourceId, PageId};
+
 #[derive(Deserialize, Debug)]
 pub struct DataSource {
-    id: String,
+    id: DataSourceId,
     name: String,
 }
 
@@ -10,5 +12,5 @@ pub struct DataSource {
 pub struct DatabaseParent {
     #[serde(rename(deserialize = "type"))]
     parent_type: String,
-    page_id: Option<String>,
+    page_id: Option<PageId>,
 }
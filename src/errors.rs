@@ -1,3 +1,5 @@
+use std::convert::Infallible;
+
 use thiserror::Error;
 
 use crate::types::ErrorResponse;
@@ -16,10 +18,38 @@ pub enum ClientError {
     #[error("RateLimited Error")]
     RateLimitedError,
 
+    #[error("Invalid ID: {0}")]
+    InvalidIdError(String),
+
+    #[error("Invalid URL: {0}")]
+    InvalidUrlError(String),
+
+    #[error("Property not found: {0}")]
+    PropertyNotFoundError(String),
+
+    #[error("Invalid property: {0}")]
+    InvalidPropertyError(String),
+
+    #[error("Read-only property: {0}")]
+    ReadOnlyPropertyError(String),
+
+    #[error("Invalid response: {0}")]
+    InvalidResponseError(String),
+
+    #[error("Checkpoint mismatch: {0}")]
+    CheckpointMismatchError(String),
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
 
+/// Lets `PageProperties` be passed where `TryInto<PageProperties>` is accepted
+impl From<Infallible> for ClientError {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
 impl From<ErrorResponse> for ClientError {
     fn from(res: ErrorResponse) -> Self {
         match res.get_code().to_lowercase().as_str() {
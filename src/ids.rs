@@ -0,0 +1,96 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{errors::ClientError, types::ClientResult};
+
+/// Accepts dashed or undashed UUIDs and returns the lowercase dashed form
+fn normalize(value: &str) -> ClientResult<String> {
+    let hex = value.trim().replace('-', "").to_lowercase();
+
+    if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ClientError::InvalidIdError(value.into()));
+    }
+
+    Ok(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+macro_rules! notion_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn parse(value: &str) -> ClientResult<Self> {
+                normalize(value).map(Self)
+            }
+
+            /// Dashed form, e.g. `28714e4a-5157-80cb-8961-000b4d54c831`
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Undashed form used in notion.so URLs
+            pub fn to_simple(&self) -> String {
+                self.0.replace('-', "")
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ClientError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ClientError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::parse(value)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Self::parse(&value).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+notion_id!(PageId);
+notion_id!(DatabaseId);
+notion_id!(DataSourceId);
+notion_id!(BlockId);
+notion_id!(UserId);
+notion_id!(CommentId);
+notion_id!(ViewId);
@@ -1,19 +1,29 @@
+use std::sync::Arc;
+
 use reqwest::Client;
 
+use crate::client::RateLimiter;
+
 pub mod client;
 pub mod errors;
+pub mod ids;
+pub mod pagination;
 pub mod types;
+pub mod url;
 
 // apis
 pub mod data_source;
 pub mod database;
 pub mod page;
 
+#[derive(Clone)]
 pub struct NotionAPI {
     client: Client,
     base_url: String,
+    limiter: Arc<RateLimiter>,
 }
 
+#[derive(Clone, Copy)]
 pub struct NotionAuthedAPI<'a> {
     api: &'a NotionAPI,
     token: &'a str,
@@ -0,0 +1,332 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    errors::ClientError,
+    ids::PageId,
+    page::types::{Page, PageParent},
+    types::{ClientResult, Cover, Icon, Property, PropertyValue, display::Renderer, user::User},
+};
+
+/// Changes between two fetches of the same page, for change notifications
+///
+/// ```ignore
+/// let diff = PageDiff::new(&before, &after)?;
+/// if !diff.is_empty() {
+///     println!("{}", diff.summary(&Renderer::new().with_locale(Locale::Ko)));
+///     let json = serde_json::to_value(&diff)?;
+/// }
+/// ```
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct PageDiff {
+    page_id: PageId,
+    edited_by: User,
+    edited_time: DateTime<Utc>,
+    properties: Vec<PropertyChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<Change<Option<Icon>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cover: Option<Change<Option<Cover>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<Change<bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_trash: Option<Change<bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<Change<PageParent>>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    old: T,
+    new: T,
+}
+
+impl<T> Change<T> {
+    pub fn get_old(&self) -> &T {
+        &self.old
+    }
+
+    pub fn get_new(&self) -> &T {
+        &self.new
+    }
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Change of a single property, matched by ID so renamed properties are compared
+///
+/// Serialized with the values as the API returned them.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct PropertyChange {
+    id: String,
+    name: String,
+    kind: ChangeKind,
+    #[serde(skip)]
+    old: Option<PropertyValue>,
+    #[serde(skip)]
+    new: Option<PropertyValue>,
+    #[serde(rename = "old", skip_serializing_if = "Value::is_null")]
+    old_raw: Value,
+    #[serde(rename = "new", skip_serializing_if = "Value::is_null")]
+    new_raw: Value,
+}
+
+impl PropertyChange {
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// Name in the newer page
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_kind(&self) -> ChangeKind {
+        self.kind
+    }
+
+    /// `None` for added properties
+    pub fn get_old(&self) -> Option<&PropertyValue> {
+        self.old.as_ref()
+    }
+
+    /// `None` for removed properties
+    pub fn get_new(&self) -> Option<&PropertyValue> {
+        self.new.as_ref()
+    }
+}
+
+impl PageDiff {
+    /// * `old` - Earlier fetch
+    /// * `new` - Later fetch of the same page
+    pub fn new(old: &Page, new: &Page) -> ClientResult<Self> {
+        if old.get_id() != new.get_id() {
+            return Err(ClientError::ValidationError(format!(
+                "Cannot diff different pages: {} and {}",
+                old.get_id(),
+                new.get_id()
+            )));
+        }
+
+        let old_properties = old.get_properties()?;
+        let new_properties = new.get_properties()?;
+        let mut properties = vec![];
+
+        for property in &new_properties {
+            let before = old_properties
+                .iter()
+                .find(|p| p.get_id() == property.get_id());
+            match before {
+                None => properties.push(PropertyChange {
+                    id: property.get_id().into(),
+                    name: property.get_name().into(),
+                    kind: ChangeKind::Added,
+                    old: None,
+                    new: Some(property.get_property_value().clone()),
+                    old_raw: Value::Null,
+                    new_raw: new.get_raw_property(property.get_name()).clone(),
+                }),
+                Some(before) if !is_same(before, property) => properties.push(PropertyChange {
+                    id: property.get_id().into(),
+                    name: property.get_name().into(),
+                    kind: ChangeKind::Changed,
+                    old: Some(before.get_property_value().clone()),
+                    new: Some(property.get_property_value().clone()),
+                    old_raw: old.get_raw_property(before.get_name()).clone(),
+                    new_raw: new.get_raw_property(property.get_name()).clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        for property in &old_properties {
+            if !new_properties
+                .iter()
+                .any(|p| p.get_id() == property.get_id())
+            {
+                properties.push(PropertyChange {
+                    id: property.get_id().into(),
+                    name: property.get_name().into(),
+                    kind: ChangeKind::Removed,
+                    old: Some(property.get_property_value().clone()),
+                    new: None,
+                    old_raw: old.get_raw_property(property.get_name()).clone(),
+                    new_raw: Value::Null,
+                });
+            }
+        }
+
+        let icon_changed = match (old.get_icon(), new.get_icon()) {
+            (Some(a), Some(b)) => !a.is_same(b),
+            (a, b) => a.is_some() != b.is_some(),
+        };
+        let cover_changed = match (old.get_cover(), new.get_cover()) {
+            (Some(a), Some(b)) => !a.get_source().is_same(b.get_source()),
+            (a, b) => a.is_some() != b.is_some(),
+        };
+
+        Ok(PageDiff {
+            page_id: new.get_id().clone(),
+            edited_by: new.get_last_edited_by().clone(),
+            edited_time: *new.get_last_edited_time(),
+            properties,
+            icon: icon_changed.then(|| Change {
+                old: old.get_icon().cloned(),
+                new: new.get_icon().cloned(),
+            }),
+            cover: cover_changed.then(|| Change {
+                old: old.get_cover().cloned(),
+                new: new.get_cover().cloned(),
+            }),
+            archived: changed(old.is_archived(), new.is_archived()),
+            in_trash: changed(old.is_in_trash(), new.is_in_trash()),
+            parent: (old.get_parent() != new.get_parent()).then(|| Change {
+                old: old.get_parent().clone(),
+                new: new.get_parent().clone(),
+            }),
+        })
+    }
+
+    pub fn get_page_id(&self) -> &PageId {
+        &self.page_id
+    }
+
+    /// `last_edited_by` of the newer page
+    pub fn get_edited_by(&self) -> &User {
+        &self.edited_by
+    }
+
+    pub fn get_edited_time(&self) -> &DateTime<Utc> {
+        &self.edited_time
+    }
+
+    /// Added and changed properties, then removed ones
+    pub fn get_properties(&self) -> &Vec<PropertyChange> {
+        &self.properties
+    }
+
+    pub fn get_property(&self, id_or_name: &str) -> Option<&PropertyChange> {
+        self.properties
+            .iter()
+            .find(|p| p.id == id_or_name || p.name == id_or_name)
+    }
+
+    pub fn get_icon(&self) -> Option<&Change<Option<Icon>>> {
+        self.icon.as_ref()
+    }
+
+    pub fn get_cover(&self) -> Option<&Change<Option<Cover>>> {
+        self.cover.as_ref()
+    }
+
+    pub fn get_archived(&self) -> Option<&Change<bool>> {
+        self.archived.as_ref()
+    }
+
+    pub fn get_in_trash(&self) -> Option<&Change<bool>> {
+        self.in_trash.as_ref()
+    }
+
+    pub fn get_parent(&self) -> Option<&Change<PageParent>> {
+        self.parent.as_ref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+            && self.icon.is_none()
+            && self.cover.is_none()
+            && self.archived.is_none()
+            && self.in_trash.is_none()
+            && self.parent.is_none()
+    }
+
+    /// One line per change, e.g. `상태: 다음행동 → 완료`
+    pub fn summary(&self, renderer: &Renderer) -> String {
+        let render = |value: Option<&PropertyValue>| match value.map(|v| renderer.render(v, None)) {
+            Some(value) if !value.is_empty() => value,
+            _ => "(empty)".into(),
+        };
+        let mut lines = vec![];
+
+        for change in &self.properties {
+            lines.push(match change.kind {
+                ChangeKind::Added => format!("+ {}: {}", change.name, render(change.get_new())),
+                ChangeKind::Removed => format!("- {}: {}", change.name, render(change.get_old())),
+                ChangeKind::Changed => format!(
+                    "{}: {} → {}",
+                    change.name,
+                    render(change.get_old()),
+                    render(change.get_new())
+                ),
+            });
+        }
+        if let Some(Change { old, new }) = &self.icon {
+            let icon = |icon: &Option<Icon>| match icon {
+                Some(icon) => icon
+                    .get_emoji()
+                    .or(icon.get_url())
+                    .unwrap_or("?")
+                    .to_string(),
+                None => "(none)".into(),
+            };
+            lines.push(format!("Icon: {} → {}", icon(old), icon(new)));
+        }
+        if let Some(Change { old, new }) = &self.cover {
+            let cover = |cover: &Option<Cover>| {
+                cover
+                    .as_ref()
+                    .and_then(|c| c.get_url())
+                    .unwrap_or("(none)")
+                    .to_string()
+            };
+            lines.push(format!("Cover: {} → {}", cover(old), cover(new)));
+        }
+        for (label, change) in [("Archived", &self.archived), ("In trash", &self.in_trash)] {
+            if let Some(Change { old, new }) = change {
+                lines.push(format!(
+                    "{}: {} → {}",
+                    label,
+                    renderer.render(&PropertyValue::Checkbox(*old), None),
+                    renderer.render(&PropertyValue::Checkbox(*new), None)
+                ));
+            }
+        }
+        if let Some(Change { old, new }) = &self.parent {
+            let parent = |parent: &PageParent| match parent.get_id() {
+                Some(id) => format!("{} {}", parent.get_type().as_str(), id),
+                None => parent.get_type().as_str().into(),
+            };
+            lines.push(format!("Parent: {} → {}", parent(old), parent(new)));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl fmt::Display for PageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.summary(&Renderer::new()))
+    }
+}
+
+fn changed(old: bool, new: bool) -> Option<Change<bool>> {
+    (old != new).then_some(Change { old, new })
+}
+
+/// Files are compared without the signature of their hosted URLs
+fn is_same(old: &Property, new: &Property) -> bool {
+    match (old.get_property_value(), new.get_property_value()) {
+        (PropertyValue::Files(a), PropertyValue::Files(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.is_same(b))
+        }
+        (a, b) => a == b,
+    }
+}
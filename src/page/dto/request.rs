@@ -1,28 +1,36 @@
 use serde::Serialize;
-use serde_json::{Value, json};
+use serde_json::Value;
 
 use crate::{
-    page::types::PageParent,
-    types::{Cover, Icon},
+    errors::ClientError,
+    page::types::{PageParent, PageProperties},
+    types::{ClientResult, Cover, Icon},
 };
 
 #[derive(Serialize)]
 pub struct PageCreateBody {
     parent: PageParent,
-    // TODO? Vec<Property> -> to_value
     properties: Value,
     icon: Option<Icon>,
     cover: Option<Cover>,
 }
 
 impl PageCreateBody {
-    pub fn new(parent: PageParent, title: &str) -> Self {
-        Self {
+    /// Page with only a title, in the default `Name` title property
+    pub fn new(parent: PageParent, title: &str) -> ClientResult<Self> {
+        Self::with_properties(parent, PageProperties::new().title("Name", title))
+    }
+
+    pub fn with_properties(
+        parent: PageParent,
+        properties: impl TryInto<PageProperties, Error: Into<ClientError>>,
+    ) -> ClientResult<Self> {
+        Ok(Self {
             parent,
-            properties: json!({"Name": {"title": [{"text": {"content": title}}]}}),
+            properties: properties.try_into().map_err(Into::into)?.to_value()?,
             icon: None,
             cover: None,
-        }
+        })
     }
 }
 
@@ -32,7 +40,11 @@ pub struct PageUpdateBody {
 }
 
 impl PageUpdateBody {
-    pub fn new(properties: Value) -> Self {
-        Self { properties }
+    pub fn new(
+        properties: impl TryInto<PageProperties, Error: Into<ClientError>>,
+    ) -> ClientResult<Self> {
+        Ok(Self {
+            properties: properties.try_into().map_err(Into::into)?.to_value()?,
+        })
     }
 }
@@ -0,0 +1,83 @@
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// `GET pages/{page_id}/properties/{property_id}`
+///
+/// title, rich_text, relation, people and rollup come back as a list of property items,
+/// every other type as a single property item.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum PropertyItemResponse {
+    List(PropertyItemList),
+    Item(Value),
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PropertyItemList {
+    results: Vec<Value>,
+    has_more: bool,
+    next_cursor: Option<String>,
+    property_item: Value,
+}
+
+impl PropertyItemList {
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    pub fn get_next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+
+    /// Merges the items (and any following pages in `rest`) back into the shape
+    /// of a page object's property value
+    ///
+    /// Rollup aggregates are only complete on the last page, so its `property_item` is used.
+    pub fn into_property_value(self, rest: Vec<PropertyItemList>) -> Value {
+        let property_item = match rest.last() {
+            Some(last) => last.property_item.clone(),
+            None => self.property_item,
+        };
+        let type_str = property_item["type"].as_str().unwrap_or_default();
+        let items = self
+            .results
+            .into_iter()
+            .chain(rest.into_iter().flat_map(|list| list.results));
+
+        match type_str {
+            "rollup" => {
+                let mut rollup = property_item["rollup"].clone();
+                if rollup["type"] == "array" {
+                    rollup["array"] = Value::Array(items.map(into_array_item).collect());
+                }
+
+                json!({"id": property_item["id"], "type": "rollup", "rollup": rollup})
+            }
+            "relation" => json!({
+                "id": property_item["id"],
+                "type": "relation",
+                "relation": items.map(|item| item["relation"].clone()).collect::<Vec<Value>>(),
+                "has_more": false,
+            }),
+            _ => json!({
+                "id": property_item["id"],
+                "type": type_str,
+                type_str: items.map(|item| item[type_str].clone()).collect::<Vec<Value>>(),
+            }),
+        }
+    }
+}
+
+/// Rollup list results come one property item per value (`"title": {...}`), while
+/// `rollup.array` holds page object property values (`"title": [...]`)
+fn into_array_item(item: Value) -> Value {
+    let type_str = item["type"].as_str().unwrap_or_default();
+
+    match type_str {
+        "title" | "rich_text" | "people" | "relation" => json!({
+            "type": type_str,
+            type_str: [item[type_str].clone()],
+        }),
+        _ => item,
+    }
+}
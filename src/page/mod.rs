@@ -1,39 +1,99 @@
-use serde_json::Value;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use serde_json::{Value, json};
 
 use crate::{
     NotionAuthedAPI,
+    errors::ClientError,
+    ids::PageId,
     page::{
-        dto::request::{PageCreateBody, PageUpdateBody},
-        types::{Page, PageParent},
+        dto::{
+            request::{PageCreateBody, PageUpdateBody},
+            response::{PropertyItemList, PropertyItemResponse},
+        },
+        types::{Page, PageParent, PageProperties},
+    },
+    types::{
+        ClientResult, Method, NotionResponse, PropertyValue, decode_property_id,
+        encode_property_id, relation::RelationValue,
     },
-    types::{ClientResult, Method, NotionResponse},
 };
 
+pub mod diff;
 pub mod dto;
 pub mod types;
 
-/// * parent - (data_source, database) and id
+/// * parent - `PageParent::new_datasource`, `new_database` or `new_page`
 pub trait PageClient: Send + Sync {
     fn create_page(
         &self,
-        parent: (&str, &str),
+        parent: PageParent,
         title: &str,
     ) -> impl Future<Output = ClientResult<NotionResponse<Page>>>;
+    /// * properties - `PageProperties` or an already shaped `Value` object
+    fn create_page_with_properties(
+        &self,
+        parent: PageParent,
+        properties: impl TryInto<PageProperties, Error: Into<ClientError>>,
+    ) -> impl Future<Output = ClientResult<NotionResponse<Page>>>;
     fn update_page(
         &self,
-        page_id: &str,
-        properties: Value,
+        page_id: &PageId,
+        properties: impl TryInto<PageProperties, Error: Into<ClientError>>,
+    ) -> impl Future<Output = ClientResult<NotionResponse<Page>>>;
+    /// Complete value of one property, following every page of property items
+    fn get_page_property(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+    ) -> impl Future<Output = ClientResult<PropertyValue>>;
+    /// Replaces values cut off at 25 items with their complete value
+    fn fill_truncated_properties(&self, page: &mut Page) -> impl Future<Output = ClientResult<()>>;
+    /// Every related page, beyond the 25 listed on the page object
+    fn get_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+    ) -> impl Future<Output = ClientResult<RelationValue>>;
+    /// Relates `page_ids` in addition to the complete current relation
+    ///
+    /// The whole relation is written back, so this fails with a `ValidationError`
+    /// once it would exceed `RELATION_WRITE_LIMIT` pages.
+    fn add_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+        page_ids: &[PageId],
+    ) -> impl Future<Output = ClientResult<NotionResponse<Page>>>;
+    /// Fails like `add_relation` when more than `RELATION_WRITE_LIMIT` pages remain
+    fn remove_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+        page_ids: &[PageId],
     ) -> impl Future<Output = ClientResult<NotionResponse<Page>>>;
 }
 
 impl<'a> PageClient for NotionAuthedAPI<'a> {
     async fn create_page(
         &self,
-        parent: (&str, &str),
+        parent: PageParent,
         title: &str,
     ) -> ClientResult<NotionResponse<Page>> {
-        let parent = PageParent::new(parent.0, parent.1);
-        let body = PageCreateBody::new(parent, title);
+        let body = PageCreateBody::new(parent, title)?;
+
+        let response = self
+            .send::<PageCreateBody, Page>("pages", Method::Post, body)
+            .await?;
+
+        Ok(response)
+    }
+
+    async fn create_page_with_properties(
+        &self,
+        parent: PageParent,
+        properties: impl TryInto<PageProperties, Error: Into<ClientError>>,
+    ) -> ClientResult<NotionResponse<Page>> {
+        let body = PageCreateBody::with_properties(parent, properties)?;
 
         let response = self
             .send::<PageCreateBody, Page>("pages", Method::Post, body)
@@ -44,11 +104,11 @@ impl<'a> PageClient for NotionAuthedAPI<'a> {
 
     async fn update_page(
         &self,
-        page_id: &str,
-        properties: Value,
+        page_id: &PageId,
+        properties: impl TryInto<PageProperties, Error: Into<ClientError>>,
     ) -> ClientResult<NotionResponse<Page>> {
         let endpoint = format!("pages/{}", page_id);
-        let body = PageUpdateBody::new(properties);
+        let body = PageUpdateBody::new(properties)?;
 
         let response = self
             .send::<PageUpdateBody, Page>(&endpoint, Method::Patch, body)
@@ -56,4 +116,127 @@ impl<'a> PageClient for NotionAuthedAPI<'a> {
 
         Ok(response)
     }
+
+    async fn get_page_property(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+    ) -> ClientResult<PropertyValue> {
+        let value = self.get_page_property_value(page_id, property_id).await?;
+
+        (&value).try_into()
+    }
+
+    async fn fill_truncated_properties(&self, page: &mut Page) -> ClientResult<()> {
+        for (name, property_id) in page.get_truncated_properties() {
+            let value = self
+                .get_page_property_value(page.get_id(), &property_id)
+                .await?;
+            page.set_property_value(&name, value);
+        }
+
+        Ok(())
+    }
+
+    async fn get_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+    ) -> ClientResult<RelationValue> {
+        match self.get_page_property(page_id, property_id).await? {
+            PropertyValue::Relation(relation) => Ok(relation),
+            other => Err(ClientError::ValidationError(format!(
+                "{} is a {} property, not a relation",
+                property_id,
+                (&other).to_string()
+            ))),
+        }
+    }
+
+    async fn add_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+        page_ids: &[PageId],
+    ) -> ClientResult<NotionResponse<Page>> {
+        let mut relation = self.get_relation(page_id, property_id).await?;
+        relation.add(page_ids);
+
+        self.update_relation(page_id, property_id, relation).await
+    }
+
+    async fn remove_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+        page_ids: &[PageId],
+    ) -> ClientResult<NotionResponse<Page>> {
+        let mut relation = self.get_relation(page_id, property_id).await?;
+        relation.remove(page_ids);
+
+        self.update_relation(page_id, property_id, relation).await
+    }
+}
+
+impl<'a> NotionAuthedAPI<'a> {
+    async fn update_relation(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+        relation: RelationValue,
+    ) -> ClientResult<NotionResponse<Page>> {
+        let properties = PageProperties::new().set(property_id, PropertyValue::Relation(relation));
+
+        self.update_page(page_id, properties).await
+    }
+
+    /// Raw property value in the same shape as on a page object
+    async fn get_page_property_value(
+        &self,
+        page_id: &PageId,
+        property_id: &str,
+    ) -> ClientResult<Value> {
+        let endpoint = format!(
+            "pages/{}/properties/{}",
+            page_id,
+            encode_property_id(&decode_property_id(property_id))
+        );
+
+        let response = self
+            .send::<Value, PropertyItemResponse>(&endpoint, Method::Get, json!({}))
+            .await?;
+
+        let list = match response.into_data()? {
+            PropertyItemResponse::Item(item) => return Ok(item),
+            PropertyItemResponse::List(list) => list,
+        };
+
+        // followed by hand, the paginator drops each page's `property_item`
+        let next_cursor = |list: &PropertyItemList| {
+            list.get_next_cursor()
+                .filter(|_| list.has_more())
+                .map(String::from)
+        };
+        let mut cursor = next_cursor(&list);
+        let mut rest = vec![];
+
+        while let Some(start_cursor) = cursor {
+            let page = self
+                .send::<Value, PropertyItemList>(
+                    &format!(
+                        "{}?start_cursor={}",
+                        endpoint,
+                        utf8_percent_encode(&start_cursor, NON_ALPHANUMERIC)
+                    ),
+                    Method::Get,
+                    json!({}),
+                )
+                .await?
+                .into_data()?;
+            cursor = next_cursor(&page);
+            rest.push(page);
+        }
+
+        Ok(list.into_property_value(rest))
+    }
 }
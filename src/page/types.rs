@@ -1,12 +1,23 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::types::{Cover, EditorInfo, Icon, Property};
+use crate::{
+    errors::ClientError,
+    ids::{BlockId, DataSourceId, DatabaseId, PageId, UserId},
+    types::{
+        ClientResult, Cover, Icon, Property, PropertyRef, PropertyValue, date::DateValue,
+        decode_property_id, file::FileObject, place::PlaceValue, rich_text::RichText,
+        select::SelectOption, user::User, verification::VerificationValue,
+    },
+};
+
+/// Page objects return at most 25 items or references per property value
+pub const PROPERTY_ITEM_LIMIT: usize = 25;
 
 #[derive(Deserialize, Debug)]
 pub struct Page {
-    id: String,
+    id: PageId,
     object: String,
 
     in_trash: Option<bool>,
@@ -23,89 +34,373 @@ pub struct Page {
     public_url: Option<String>,
     url: String,
 
-    created_by: EditorInfo,
+    created_by: User,
     created_time: DateTime<Utc>,
-    last_edited_by: EditorInfo,
+    last_edited_by: User,
     last_edited_time: DateTime<Utc>,
 }
 
 impl Page {
-    pub fn get_id(&self) -> &str {
+    pub fn get_id(&self) -> &PageId {
         &self.id
     }
 
-    pub fn get_properties(&self) -> Vec<Property> {
+    pub fn get_created_by(&self) -> &User {
+        &self.created_by
+    }
+
+    pub fn get_last_edited_by(&self) -> &User {
+        &self.last_edited_by
+    }
+
+    pub fn get_last_edited_time(&self) -> &DateTime<Utc> {
+        &self.last_edited_time
+    }
+
+    pub fn get_icon(&self) -> Option<&Icon> {
+        self.icon.as_ref()
+    }
+
+    pub fn get_cover(&self) -> Option<&Cover> {
+        self.cover.as_ref()
+    }
+
+    pub fn get_parent(&self) -> &PageParent {
+        &self.parent
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn is_in_trash(&self) -> bool {
+        self.in_trash.unwrap_or(self.archived)
+    }
+
+    /// Property as returned by the API, keyed by name
+    pub(crate) fn get_raw_property(&self, name: &str) -> &Value {
+        &self.properties[name]
+    }
+
+    pub fn get_properties(&self) -> ClientResult<Vec<Property>> {
         self.properties
             .as_object()
-            .unwrap()
-            .iter()
+            .into_iter()
+            .flatten()
             .map(|(n, v)| Property::new(n, v))
-            .collect::<Vec<Property>>()
+            .collect::<ClientResult<Vec<Property>>>()
+    }
+
+    pub fn get_property(&self, property: &PropertyRef) -> ClientResult<Option<Property>> {
+        Ok(self.get_properties()?.into_iter().find(|p| p.is(property)))
+    }
+
+    /// Verification of a wiki page, `None` outside wikis
+    pub fn get_verification(&self) -> ClientResult<Option<VerificationValue>> {
+        Ok(self
+            .get_properties()?
+            .into_iter()
+            .find_map(|p| match p.get_property_value() {
+                PropertyValue::Verification(v) => Some(v.clone()),
+                _ => None,
+            }))
+    }
+
+    /// (name, id) of properties whose value may be cut off at `PROPERTY_ITEM_LIMIT`
+    ///
+    /// Relations report `has_more`. Title, rich text, people and rollup arrays don't, so
+    /// those are listed once they hold `PROPERTY_ITEM_LIMIT` items, even if that is all.
+    pub fn get_truncated_properties(&self) -> Vec<(String, String)> {
+        self.properties
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(_, v)| is_truncated(v))
+            .map(|(n, v)| {
+                (
+                    n.clone(),
+                    decode_property_id(v["id"].as_str().unwrap_or_default()),
+                )
+            })
+            .collect::<Vec<(String, String)>>()
+    }
+
+    pub(crate) fn set_property_value(&mut self, name: &str, value: Value) {
+        self.properties[name] = value;
+    }
+}
+
+/// Wiki pages whose verification has expired or expires within `within` of `now`
+///
+/// Fails on the first page whose properties can't be parsed instead of leaving it out.
+pub fn find_stale_pages(
+    pages: &[Page],
+    now: DateTime<Utc>,
+    within: Duration,
+) -> ClientResult<Vec<&Page>> {
+    let mut stale = vec![];
+
+    for page in pages {
+        if page
+            .get_verification()?
+            .is_some_and(|v| v.is_expiring(now, within))
+        {
+            stale.push(page);
+        }
+    }
+
+    Ok(stale)
+}
+
+fn is_truncated(value: &Value) -> bool {
+    let type_str = value["type"].as_str().unwrap_or_default();
+    let is_full = |items: &Value| {
+        items
+            .as_array()
+            .is_some_and(|items| items.len() >= PROPERTY_ITEM_LIMIT)
+    };
+
+    match type_str {
+        "relation" => value["has_more"].as_bool().unwrap_or(false),
+        "title" | "rich_text" | "people" => is_full(&value[type_str]),
+        "rollup" => is_full(&value["rollup"]["array"]),
+        _ => false,
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ParentType {
     DatabaseId,
     DataSourceId,
     PageId,
+    BlockId,
+    Workspace,
 }
 
-impl From<&str> for ParentType {
-    fn from(s: &str) -> Self {
-        match s {
-            "page" => ParentType::PageId,
-            "database" => ParentType::DatabaseId,
-            _ => ParentType::DataSourceId,
-        }
-    }
-}
-
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct PageParent {
     #[serde(rename(deserialize = "type", serialize = "type"))]
     parent_type: ParentType,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    database_id: Option<String>,
+    database_id: Option<DatabaseId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_source_id: Option<DataSourceId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    data_source_id: Option<String>,
+    page_id: Option<PageId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    page_id: Option<String>,
+    block_id: Option<BlockId>,
 }
 
 impl PageParent {
-    pub fn new(type_str: &str, id: &str) -> Self {
-        match Into::<ParentType>::into(type_str) {
-            ParentType::DataSourceId => Self::new_datasource(id),
-            ParentType::PageId => Self::new_page(id),
-            ParentType::DatabaseId => Self::new_database(id),
-        }
-    }
-
-    pub fn new_datasource(id: &str) -> Self {
+    pub fn new_datasource(id: &DataSourceId) -> Self {
         Self {
             parent_type: ParentType::DataSourceId,
             database_id: None,
-            data_source_id: Some(id.into()),
+            data_source_id: Some(id.clone()),
             page_id: None,
+            block_id: None,
         }
     }
-    pub fn new_database(id: &str) -> Self {
+
+    pub fn new_database(id: &DatabaseId) -> Self {
         Self {
             parent_type: ParentType::DatabaseId,
-            database_id: Some(id.into()),
+            database_id: Some(id.clone()),
             data_source_id: None,
             page_id: None,
+            block_id: None,
         }
     }
-    pub fn new_page(id: &str) -> Self {
+
+    pub fn new_page(id: &PageId) -> Self {
         Self {
             parent_type: ParentType::PageId,
             database_id: None,
             data_source_id: None,
-            page_id: Some(id.into()),
+            page_id: Some(id.clone()),
+            block_id: None,
+        }
+    }
+
+    pub fn get_type(&self) -> ParentType {
+        self.parent_type
+    }
+
+    /// `None` for workspace parents
+    pub fn get_id(&self) -> Option<String> {
+        match self.parent_type {
+            ParentType::DatabaseId => self.database_id.as_ref().map(|id| id.to_string()),
+            ParentType::DataSourceId => self.data_source_id.as_ref().map(|id| id.to_string()),
+            ParentType::PageId => self.page_id.as_ref().map(|id| id.to_string()),
+            ParentType::BlockId => self.block_id.as_ref().map(|id| id.to_string()),
+            ParentType::Workspace => None,
+        }
+    }
+}
+
+impl ParentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParentType::DatabaseId => "database_id",
+            ParentType::DataSourceId => "data_source_id",
+            ParentType::PageId => "page_id",
+            ParentType::BlockId => "block_id",
+            ParentType::Workspace => "workspace",
+        }
+    }
+}
+
+/// Property values for creating or updating a page, keyed by property name or ID
+///
+/// ```ignore
+/// let properties = PageProperties::new()
+///     .title("Name", "회의록")
+///     .date("날짜", NaiveDate::from_ymd_opt(2025, 11, 27).unwrap())
+///     .select("상태", "다음행동");
+/// ```
+#[derive(Default, Debug)]
+pub struct PageProperties {
+    values: Vec<(String, PropertyValue)>,
+    raw: serde_json::Map<String, Value>,
+}
+
+impl PageProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces an earlier value of the same property
+    pub fn set(mut self, property: &str, value: PropertyValue) -> Self {
+        self.raw.remove(property);
+        self.values.retain(|(p, _)| p != property);
+        self.values.push((property.into(), value));
+        self
+    }
+
+    pub fn title(self, property: &str, title: &str) -> Self {
+        self.set(property, PropertyValue::Title(vec![RichText::text(title)]))
+    }
+
+    pub fn rich_text(self, property: &str, texts: Vec<RichText>) -> Self {
+        self.set(property, PropertyValue::RichText(texts))
+    }
+
+    pub fn number(self, property: &str, number: f64) -> Self {
+        self.set(property, PropertyValue::Number(Some(number)))
+    }
+
+    pub fn checkbox(self, property: &str, checked: bool) -> Self {
+        self.set(property, PropertyValue::Checkbox(checked))
+    }
+
+    pub fn date(self, property: &str, date: impl Into<DateValue>) -> Self {
+        self.set(property, PropertyValue::Date(Some(date.into())))
+    }
+
+    /// * option - name (`"일정"`) or `SelectOption::by_id`
+    pub fn select(self, property: &str, option: impl Into<SelectOption>) -> Self {
+        self.set(property, PropertyValue::Select(Some(option.into())))
+    }
+
+    pub fn multi_select<O: Into<SelectOption>>(
+        self,
+        property: &str,
+        options: impl IntoIterator<Item = O>,
+    ) -> Self {
+        let options = options.into_iter().map(|o| o.into()).collect();
+        self.set(property, PropertyValue::MultiSelect(options))
+    }
+
+    pub fn status(self, property: &str, option: impl Into<SelectOption>) -> Self {
+        self.set(property, PropertyValue::Status(Some(option.into())))
+    }
+
+    pub fn url(self, property: &str, url: &str) -> Self {
+        self.set(property, PropertyValue::Url(Some(url.into())))
+    }
+
+    pub fn email(self, property: &str, email: &str) -> Self {
+        self.set(property, PropertyValue::Email(Some(email.into())))
+    }
+
+    pub fn phone_number(self, property: &str, phone_number: &str) -> Self {
+        self.set(
+            property,
+            PropertyValue::PhoneNumber(Some(phone_number.into())),
+        )
+    }
+
+    /// Replaces the attached files, include the current ones to keep them
+    pub fn files(self, property: &str, files: Vec<FileObject>) -> Self {
+        self.set(property, PropertyValue::Files(files))
+    }
+
+    pub fn place(self, property: &str, place: PlaceValue) -> Self {
+        self.set(property, PropertyValue::Place(Some(place)))
+    }
+
+    pub fn people(self, property: &str, users: &[UserId]) -> Self {
+        let users = users.iter().map(User::new).collect();
+        self.set(property, PropertyValue::People(users))
+    }
+
+    pub fn relation(self, property: &str, pages: &[PageId]) -> Self {
+        self.set(property, PropertyValue::Relation(pages.to_vec().into()))
+    }
+
+    /// Marks a wiki page verified, for `date`'s period or indefinitely
+    pub fn verify(self, property: &str, date: Option<DateValue>) -> Self {
+        self.set(
+            property,
+            PropertyValue::Verification(VerificationValue::verified(date)),
+        )
+    }
+
+    pub fn unverify(self, property: &str) -> Self {
+        self.set(
+            property,
+            PropertyValue::Verification(VerificationValue::unverified()),
+        )
+    }
+
+    /// Empties a property, e.g. `clear("날짜", "date")`
+    ///
+    /// * type_str - property type from the schema, read-only types are rejected
+    pub fn clear(self, property: &str, type_str: &str) -> ClientResult<Self> {
+        Ok(self.set(property, PropertyValue::empty(type_str)?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.raw.is_empty()
+    }
+
+    /// `properties` object of the request body
+    pub fn to_value(&self) -> ClientResult<Value> {
+        let mut properties = self.raw.clone();
+        for (property, value) in &self.values {
+            properties.insert(property.clone(), value.to_write_value()?);
+        }
+
+        Ok(Value::Object(properties))
+    }
+}
+
+/// Already shaped payload, e.g. `json!({"날짜": {"date": {"start": "2025-11-27"}}})`
+impl TryFrom<Value> for PageProperties {
+    type Error = ClientError;
+
+    fn try_from(value: Value) -> ClientResult<Self> {
+        match value {
+            Value::Object(raw) => Ok(Self {
+                values: Vec::new(),
+                raw,
+            }),
+            other => Err(ClientError::ValidationError(format!(
+                "page properties must be an object keyed by property, got {}",
+                other
+            ))),
         }
     }
 }
@@ -0,0 +1,670 @@
+use std::{fmt, marker::PhantomData, pin::pin};
+
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::{
+    NotionAPI, NotionAuthedAPI,
+    errors::ClientError,
+    types::{ClientResult, Method},
+};
+
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Cursor based list object returned by every paginated endpoint
+#[derive(Deserialize, Debug)]
+pub struct PaginatedList<T> {
+    results: Vec<T>,
+    has_more: bool,
+    next_cursor: Option<String>,
+}
+
+impl<T> PaginatedList<T> {
+    #[cfg(test)]
+    fn new(results: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            results,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        }
+    }
+
+    pub fn get_results(&self) -> &Vec<T> {
+        &self.results
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    pub fn get_next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+
+    pub fn into_results(self) -> Vec<T> {
+        self.results
+    }
+}
+
+/// Position of a paginated walk that can be saved to disk and resumed later
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PaginationCheckpoint {
+    endpoint: String,
+    body_hash: String,
+    cursor: Option<String>,
+    items_seen: usize,
+    has_more: bool,
+}
+
+impl PaginationCheckpoint {
+    /// Checkpoint before the first request
+    pub fn new(endpoint: &str, body: &Value) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            body_hash: body_hash(body),
+            cursor: None,
+            items_seen: 0,
+            has_more: true,
+        }
+    }
+
+    pub fn get_endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub fn get_cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    pub fn get_items_seen(&self) -> usize {
+        self.items_seen
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// Fails when the checkpoint was taken for another endpoint or request body
+    pub fn verify(&self, endpoint: &str, body: &Value) -> ClientResult<()> {
+        if self.endpoint != endpoint {
+            return Err(ClientError::CheckpointMismatchError(format!(
+                "endpoint changed from {} to {}",
+                self.endpoint, endpoint
+            )));
+        }
+        if self.body_hash != body_hash(body) {
+            return Err(ClientError::CheckpointMismatchError(format!(
+                "query parameters changed for {}",
+                endpoint
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Results of one request together with the checkpoint right after it
+#[derive(Debug)]
+pub struct CheckpointedPage<T> {
+    results: Vec<T>,
+    checkpoint: PaginationCheckpoint,
+}
+
+impl<T> CheckpointedPage<T> {
+    pub fn get_results(&self) -> &Vec<T> {
+        &self.results
+    }
+
+    pub fn get_checkpoint(&self) -> &PaginationCheckpoint {
+        &self.checkpoint
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, PaginationCheckpoint) {
+        (self.results, self.checkpoint)
+    }
+}
+
+/// FNV-1a over the serialized body, stable across builds so it can be stored
+fn body_hash(body: &Value) -> String {
+    let hash = body
+        .to_string()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        });
+
+    format!("{:016x}", hash)
+}
+
+/// Makes the request for one page of a walk
+pub(crate) trait PageFetcher<T>: Send + Sync + 'static {
+    fn fetch_page(
+        &self,
+        cursor: Option<&str>,
+        page_size: Option<u32>,
+    ) -> impl Future<Output = ClientResult<PaginatedList<T>>> + Send;
+}
+
+/// Fetches pages of a Notion list endpoint.
+///
+/// `start_cursor` and `page_size` are sent in the query string for `GET` endpoints
+/// (block children, users, comments, property items) and in the body for `POST`
+/// endpoints (data source query, search).
+pub(crate) struct ApiFetcher {
+    api: NotionAPI,
+    token: String,
+    endpoint: String,
+    method: Method,
+    body: Value,
+}
+
+impl<T> PageFetcher<T> for ApiFetcher
+where
+    T: DeserializeOwned + fmt::Debug + Send + 'static,
+{
+    fn fetch_page(
+        &self,
+        cursor: Option<&str>,
+        page_size: Option<u32>,
+    ) -> impl Future<Output = ClientResult<PaginatedList<T>>> + Send {
+        let mut endpoint = self.endpoint.clone();
+        let mut body = self.body.clone();
+
+        match self.method {
+            Method::Get | Method::Delete => endpoint = with_query(&endpoint, cursor, page_size),
+            Method::Post | Method::Patch => {
+                if let Some(object) = body.as_object_mut() {
+                    if let Some(cursor) = cursor {
+                        object.insert("start_cursor".into(), cursor.into());
+                    }
+                    if let Some(size) = page_size {
+                        object.insert("page_size".into(), size.into());
+                    }
+                }
+            }
+        }
+
+        async move {
+            let response = self
+                .api
+                .authed(&self.token)
+                .send::<Value, PaginatedList<T>>(&endpoint, self.method, body)
+                .await?;
+
+            response.into_data()
+        }
+    }
+}
+
+/// Cursors are opaque and may contain `+`, `/` or `=`, so they are percent-encoded
+fn with_query(endpoint: &str, cursor: Option<&str>, page_size: Option<u32>) -> String {
+    let params = cursor
+        .map(|c| format!("start_cursor={}", utf8_percent_encode(c, NON_ALPHANUMERIC)))
+        .into_iter()
+        .chain(page_size.map(|s| format!("page_size={}", s)))
+        .collect::<Vec<String>>();
+
+    if params.is_empty() {
+        return endpoint.into();
+    }
+    let separator = if endpoint.contains('?') { '&' } else { '?' };
+
+    format!("{}{}{}", endpoint, separator, params.join("&"))
+}
+
+/// Walks a list endpoint by following `next_cursor`.
+///
+/// With `prefetch(depth)` the requests run in a background task that stays up to
+/// `depth` pages ahead of the consumer, sharing the client's rate limiter.
+pub struct Paginator<'a, T> {
+    walk: Walk<ApiFetcher>,
+    _marker: PhantomData<fn(&'a ()) -> T>,
+}
+
+impl<'a> NotionAuthedAPI<'a> {
+    pub fn paginate<T>(&self, endpoint: &str, method: Method, body: Value) -> Paginator<'a, T>
+    where
+        T: DeserializeOwned + fmt::Debug + Send + 'static,
+    {
+        let fetcher = ApiFetcher {
+            api: self.api.clone(),
+            token: self.token.into(),
+            endpoint: endpoint.into(),
+            method,
+            body: body.clone(),
+        };
+
+        Paginator {
+            walk: Walk::new(fetcher, endpoint, body),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Paginator<'a, T>
+where
+    T: DeserializeOwned + fmt::Debug + Send + 'static,
+{
+    fn map(self, f: impl FnOnce(Walk<ApiFetcher>) -> Walk<ApiFetcher>) -> Self {
+        Paginator {
+            walk: f(self.walk),
+            _marker: PhantomData,
+        }
+    }
+
+    /// * `size` - items per request, clamped to 1..=100
+    pub fn page_size(self, size: u32) -> Self {
+        self.map(|walk| walk.page_size(size))
+    }
+
+    /// Stops after `max` items even if the endpoint has more
+    pub fn max_items(self, max: usize) -> Self {
+        self.map(|walk| walk.max_items(max))
+    }
+
+    /// Pages fetched ahead of the consumer. `0` (default) fetches only when polled.
+    pub fn prefetch(self, depth: usize) -> Self {
+        self.map(|walk| walk.prefetch(depth))
+    }
+
+    /// Continues from a saved checkpoint. `max_items` keeps counting from its `items_seen`.
+    pub fn resume_from(mut self, checkpoint: PaginationCheckpoint) -> ClientResult<Self> {
+        self.walk = self.walk.resume_from(checkpoint)?;
+        Ok(self)
+    }
+
+    pub fn checkpoint(&self) -> PaginationCheckpoint {
+        self.walk.checkpoint()
+    }
+
+    /// Yields one `Vec<T>` per request
+    pub fn into_page_stream(self) -> impl Stream<Item = ClientResult<Vec<T>>> + 'a {
+        self.into_checkpoint_stream().map_ok(|page| page.results)
+    }
+
+    /// Yields every request's results with a checkpoint to resume after them
+    pub fn into_checkpoint_stream(
+        self,
+    ) -> impl Stream<Item = ClientResult<CheckpointedPage<T>>> + 'a {
+        self.walk.into_checkpoint_stream()
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = ClientResult<T>> + 'a {
+        self.into_page_stream()
+            .map_ok(|results| stream::iter(results.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    pub async fn collect_all(self) -> ClientResult<Vec<T>> {
+        self.into_stream().try_collect().await
+    }
+}
+
+/// Settings and position of a walk over any `PageFetcher`
+struct Walk<F> {
+    fetcher: F,
+    endpoint: String,
+    body: Value,
+    page_size: Option<u32>,
+    max_items: Option<usize>,
+    resume: Option<PaginationCheckpoint>,
+    prefetch: usize,
+}
+
+struct PageState {
+    cursor: Option<String>,
+    seen: usize,
+    done: bool,
+}
+
+impl<F> Walk<F> {
+    /// * `endpoint`, `body` - What checkpoints are verified against
+    fn new(fetcher: F, endpoint: &str, body: Value) -> Self {
+        Walk {
+            fetcher,
+            endpoint: endpoint.into(),
+            body,
+            page_size: None,
+            max_items: None,
+            resume: None,
+            prefetch: 0,
+        }
+    }
+
+    fn page_size(mut self, size: u32) -> Self {
+        self.page_size = Some(size.clamp(1, MAX_PAGE_SIZE));
+        self
+    }
+
+    fn max_items(mut self, max: usize) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+
+    fn prefetch(mut self, depth: usize) -> Self {
+        self.prefetch = depth;
+        self
+    }
+
+    fn resume_from(mut self, checkpoint: PaginationCheckpoint) -> ClientResult<Self> {
+        checkpoint.verify(&self.endpoint, &self.body)?;
+        self.resume = Some(checkpoint);
+        Ok(self)
+    }
+
+    fn checkpoint(&self) -> PaginationCheckpoint {
+        self.resume
+            .clone()
+            .unwrap_or_else(|| PaginationCheckpoint::new(&self.endpoint, &self.body))
+    }
+
+    fn into_checkpoint_stream<T>(self) -> impl Stream<Item = ClientResult<CheckpointedPage<T>>>
+    where
+        T: Send + 'static,
+        F: PageFetcher<T>,
+    {
+        if self.prefetch == 0 {
+            return self.sequential_stream().left_stream();
+        }
+
+        let (tx, rx) = mpsc::channel(self.prefetch);
+        tokio::spawn(async move {
+            let mut pages = pin!(self.sequential_stream());
+
+            while let Some(page) = pages.next().await {
+                let failed = page.is_err();
+                // receiver dropped: the consumer stopped reading
+                if tx.send(page).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|page| (page, rx))
+        })
+        .right_stream()
+    }
+
+    fn sequential_stream<T>(self) -> impl Stream<Item = ClientResult<CheckpointedPage<T>>>
+    where
+        T: Send + 'static,
+        F: PageFetcher<T>,
+    {
+        let checkpoint = self.checkpoint();
+        let state = PageState {
+            done: !checkpoint.has_more
+                || self
+                    .max_items
+                    .is_some_and(|max| checkpoint.items_seen >= max),
+            cursor: checkpoint.cursor,
+            seen: checkpoint.items_seen,
+        };
+        let body_hash = checkpoint.body_hash;
+
+        stream::try_unfold((self, state), move |(walk, mut state)| {
+            let body_hash = body_hash.clone();
+            async move {
+                if state.done {
+                    return Ok(None);
+                }
+
+                let remaining = walk.max_items.map(|max| max - state.seen);
+                let page_size = match (walk.page_size, remaining) {
+                    (Some(size), Some(remaining)) => {
+                        Some(size.min(remaining.min(MAX_PAGE_SIZE as usize) as u32))
+                    }
+                    (None, Some(remaining)) if remaining < MAX_PAGE_SIZE as usize => {
+                        Some(remaining as u32)
+                    }
+                    (size, _) => size,
+                };
+
+                let list = walk
+                    .fetcher
+                    .fetch_page(state.cursor.as_deref(), page_size)
+                    .await?;
+
+                let mut results = list.results;
+                if let Some(remaining) = remaining {
+                    results.truncate(remaining);
+                }
+
+                state.seen += results.len();
+                state.cursor = list.next_cursor;
+                state.done = !list.has_more
+                    || state.cursor.is_none()
+                    || walk.max_items.is_some_and(|max| state.seen >= max);
+
+                let checkpoint = PaginationCheckpoint {
+                    endpoint: walk.endpoint.clone(),
+                    body_hash,
+                    cursor: state.cursor.clone(),
+                    items_seen: state.seen,
+                    has_more: list.has_more && state.cursor.is_some(),
+                };
+
+                Ok(Some((
+                    CheckpointedPage {
+                        results,
+                        checkpoint,
+                    },
+                    (walk, state),
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use futures::{StreamExt, TryStreamExt};
+    use serde_json::json;
+
+    use super::{PageFetcher, PaginatedList, Walk, with_query};
+    use crate::types::ClientResult;
+
+    type Request = (Option<String>, Option<u32>);
+
+    /// Serves `0..total` in pages of at most 100, recording every request
+    #[derive(Clone)]
+    struct MockFetcher {
+        total: usize,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockFetcher {
+        fn new(total: usize) -> Self {
+            Self {
+                total,
+                requests: Arc::default(),
+            }
+        }
+
+        fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn walk(&self) -> Walk<MockFetcher> {
+            Walk::new(self.clone(), "mock", json!({}))
+        }
+    }
+
+    impl PageFetcher<usize> for MockFetcher {
+        async fn fetch_page(
+            &self,
+            cursor: Option<&str>,
+            page_size: Option<u32>,
+        ) -> ClientResult<PaginatedList<usize>> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((cursor.map(String::from), page_size));
+
+            assert_ne!(page_size, Some(0), "the API rejects page_size=0");
+            let start = cursor.map_or(0, |c| c.parse().unwrap());
+            let end = self.total.min(start + page_size.unwrap_or(100) as usize);
+            let next_cursor = (end < self.total).then(|| end.to_string());
+
+            Ok(PaginatedList::new((start..end).collect(), next_cursor))
+        }
+    }
+
+    async fn collect_all(walk: Walk<MockFetcher>) -> ClientResult<Vec<usize>> {
+        walk.into_checkpoint_stream::<usize>()
+            .map_ok(|page| page.results)
+            .try_concat()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_walk_collect_all() {
+        let fetcher = MockFetcher::new(250);
+        let items = collect_all(fetcher.walk()).await.unwrap();
+
+        assert_eq!(items, (0..250).collect::<Vec<_>>());
+        assert_eq!(
+            fetcher.requests(),
+            vec![
+                (None, None),
+                (Some("100".into()), None),
+                (Some("200".into()), None)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_walk_max_items() {
+        let fetcher = MockFetcher::new(250);
+        let items = collect_all(fetcher.walk().page_size(100).max_items(150))
+            .await
+            .unwrap();
+
+        assert_eq!(items, (0..150).collect::<Vec<_>>());
+        assert_eq!(
+            fetcher.requests(),
+            vec![(None, Some(100)), (Some("100".into()), Some(50))]
+        );
+
+        // without a page size only the last request is shrunk
+        let fetcher = MockFetcher::new(250);
+        let items = collect_all(fetcher.walk().max_items(30)).await.unwrap();
+
+        assert_eq!(items.len(), 30);
+        assert_eq!(fetcher.requests(), vec![(None, Some(30))]);
+    }
+
+    #[tokio::test]
+    async fn test_walk_page_size_clamp() {
+        let fetcher = MockFetcher::new(5);
+        collect_all(fetcher.walk().page_size(500)).await.unwrap();
+        assert_eq!(fetcher.requests(), vec![(None, Some(100))]);
+
+        let fetcher = MockFetcher::new(2);
+        let items = collect_all(fetcher.walk().page_size(0)).await.unwrap();
+        assert_eq!(items, vec![0, 1]);
+        assert_eq!(
+            fetcher.requests(),
+            vec![(None, Some(1)), (Some("1".into()), Some(1))]
+        );
+
+        // 2^32 must not wrap to `page_size=0`
+        let fetcher = MockFetcher::new(5);
+        collect_all(fetcher.walk().page_size(100).max_items(1 << 32))
+            .await
+            .unwrap();
+        assert_eq!(fetcher.requests(), vec![(None, Some(100))]);
+    }
+
+    #[tokio::test]
+    async fn test_walk_resume() {
+        let fetcher = MockFetcher::new(250);
+        let mut pages = Box::pin(
+            fetcher
+                .walk()
+                .max_items(220)
+                .into_checkpoint_stream::<usize>(),
+        );
+        let (first, checkpoint) = pages.next().await.unwrap().unwrap().into_parts();
+        drop(pages);
+
+        assert_eq!(first, (0..100).collect::<Vec<_>>());
+        assert_eq!(checkpoint.get_cursor(), Some("100"));
+        assert_eq!(checkpoint.get_items_seen(), 100);
+        assert!(checkpoint.has_more());
+
+        let saved = serde_json::to_string(&checkpoint).unwrap();
+        let fetcher = MockFetcher::new(250);
+        let walk = fetcher
+            .walk()
+            .max_items(220)
+            .resume_from(serde_json::from_str(&saved).unwrap())
+            .unwrap();
+        let rest = collect_all(walk).await.unwrap();
+
+        assert_eq!(rest, (100..220).collect::<Vec<_>>());
+        assert_eq!(
+            fetcher.requests(),
+            vec![(Some("100".into()), None), (Some("200".into()), Some(20))]
+        );
+
+        // finished walks make no requests
+        let fetcher = MockFetcher::new(50);
+        let mut pages = Box::pin(fetcher.walk().into_checkpoint_stream::<usize>());
+        let (_, checkpoint) = pages.next().await.unwrap().unwrap().into_parts();
+        assert!(!checkpoint.has_more());
+
+        let fetcher = MockFetcher::new(50);
+        let rest = collect_all(fetcher.walk().resume_from(checkpoint).unwrap())
+            .await
+            .unwrap();
+        assert!(rest.is_empty());
+        assert!(fetcher.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_walk_prefetch() {
+        let fetcher = MockFetcher::new(350);
+        let mut pages = Box::pin(fetcher.walk().prefetch(1).into_checkpoint_stream::<usize>());
+
+        let first = pages.next().await.unwrap().unwrap();
+        assert_eq!(first.get_results(), &(0..100).collect::<Vec<_>>());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        // the next page is fetched while the consumer holds the first
+        assert!(fetcher.requests().len() >= 2);
+
+        let mut items = first.results;
+        while let Some(page) = pages.next().await {
+            items.extend(page.unwrap().results);
+        }
+        assert_eq!(items, (0..350).collect::<Vec<_>>());
+        assert_eq!(fetcher.requests().len(), 4);
+
+        let fetcher = MockFetcher::new(350);
+        let mut pages = Box::pin(fetcher.walk().into_checkpoint_stream::<usize>());
+        pages.next().await.unwrap().unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(fetcher.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_query_cursor_encoding() {
+        assert_eq!(
+            with_query("users", Some("a+b/c=&d"), Some(50)),
+            "users?start_cursor=a%2Bb%2Fc%3D%26d&page_size=50"
+        );
+        assert_eq!(
+            with_query("comments?block_id=1", Some("abc"), None),
+            "comments?block_id=1&start_cursor=abc"
+        );
+        assert_eq!(with_query("users", None, None), "users");
+    }
+}
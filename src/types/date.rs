@@ -0,0 +1,186 @@
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{errors::ClientError, types::ClientResult};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const NAIVE_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// `start` / `end` of a date value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateTimeValue {
+    /// `2025-11-27`
+    Date(NaiveDate),
+    /// `2025-11-11T22:00:00+09:00`
+    DateTime(DateTime<FixedOffset>),
+    /// `2025-11-11T22:00:00`, only meaningful together with a `time_zone`
+    Floating(NaiveDateTime),
+}
+
+impl DateTimeValue {
+    pub fn parse(value: &str) -> ClientResult<Self> {
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+            return Ok(DateTimeValue::DateTime(datetime));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(value, DATE_FORMAT) {
+            return Ok(DateTimeValue::Date(date));
+        }
+        NaiveDateTime::parse_from_str(value, NAIVE_DATETIME_FORMAT)
+            .map(DateTimeValue::Floating)
+            .map_err(|_| ClientError::ValidationError(format!("Invalid date: {}", value)))
+    }
+
+    pub fn is_date_only(&self) -> bool {
+        matches!(self, DateTimeValue::Date(_))
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            DateTimeValue::Date(date) => *date,
+            DateTimeValue::DateTime(datetime) => datetime.date_naive(),
+            DateTimeValue::Floating(datetime) => datetime.date(),
+        }
+    }
+
+    /// `None` for date-only and floating values
+    pub fn datetime(&self) -> Option<DateTime<FixedOffset>> {
+        match self {
+            DateTimeValue::DateTime(datetime) => Some(*datetime),
+            _ => None,
+        }
+    }
+
+    /// Date-only values become midnight in `offset`
+    pub fn to_datetime_in(&self, offset: &FixedOffset) -> Option<DateTime<FixedOffset>> {
+        match self {
+            DateTimeValue::DateTime(datetime) => Some(datetime.with_timezone(offset)),
+            DateTimeValue::Date(date) => offset
+                .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+                .single(),
+            DateTimeValue::Floating(datetime) => offset.from_local_datetime(datetime).single(),
+        }
+    }
+}
+
+impl fmt::Display for DateTimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateTimeValue::Date(date) => write!(f, "{}", date.format(DATE_FORMAT)),
+            DateTimeValue::DateTime(datetime) => write!(f, "{}", datetime.to_rfc3339()),
+            DateTimeValue::Floating(datetime) => {
+                write!(f, "{}", datetime.format(NAIVE_DATETIME_FORMAT))
+            }
+        }
+    }
+}
+
+impl From<NaiveDate> for DateTimeValue {
+    fn from(date: NaiveDate) -> Self {
+        DateTimeValue::Date(date)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for DateTimeValue {
+    fn from(datetime: DateTime<Tz>) -> Self {
+        DateTimeValue::DateTime(datetime.fixed_offset())
+    }
+}
+
+impl Serialize for DateTimeValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        DateTimeValue::parse(&value).map_err(de::Error::custom)
+    }
+}
+
+/// Value of a date property or date mention
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct DateValue {
+    start: DateTimeValue,
+    end: Option<DateTimeValue>,
+    /// IANA time zone, e.g. `Asia/Seoul`
+    #[serde(default)]
+    time_zone: Option<String>,
+}
+
+impl DateValue {
+    pub fn new(start: impl Into<DateTimeValue>) -> Self {
+        Self {
+            start: start.into(),
+            end: None,
+            time_zone: None,
+        }
+    }
+
+    pub fn date(date: NaiveDate) -> Self {
+        Self::new(date)
+    }
+
+    pub fn datetime<Tz: TimeZone>(datetime: DateTime<Tz>) -> Self {
+        Self::new(datetime)
+    }
+
+    pub fn range(start: impl Into<DateTimeValue>, end: impl Into<DateTimeValue>) -> Self {
+        Self::new(start).with_end(end)
+    }
+
+    pub fn with_end(mut self, end: impl Into<DateTimeValue>) -> Self {
+        self.end = Some(end.into());
+        self
+    }
+
+    /// Notion reads `start`/`end` in this zone; pair it with `Floating` values
+    pub fn with_time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    pub fn get_start(&self) -> &DateTimeValue {
+        &self.start
+    }
+
+    pub fn get_end(&self) -> Option<&DateTimeValue> {
+        self.end.as_ref()
+    }
+
+    pub fn get_time_zone(&self) -> Option<&str> {
+        self.time_zone.as_deref()
+    }
+
+    pub fn is_date_only(&self) -> bool {
+        self.start.is_date_only() && self.end.is_none_or(|end| end.is_date_only())
+    }
+
+    pub fn is_range(&self) -> bool {
+        self.end.is_some()
+    }
+}
+
+impl fmt::Display for DateValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.end {
+            Some(end) => write!(f, "{}~{}", self.start, end),
+            None => write!(f, "{}", self.start),
+        }
+    }
+}
+
+impl From<NaiveDate> for DateValue {
+    fn from(date: NaiveDate) -> Self {
+        Self::date(date)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for DateValue {
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self::datetime(datetime)
+    }
+}
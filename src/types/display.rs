@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::{
+    data_source::types::{Property as SchemaProperty, PropertyInfo},
+    ids::{PageId, UserId},
+    page::types::Page,
+    types::{
+        ClientResult, PropertyValue,
+        date::{DateTimeValue, DateValue},
+        formula::FormulaValue,
+        rich_text::to_plain_text,
+        rollup::RollupResult,
+        user::User,
+        verification::VerificationState,
+    },
+};
+
+/// Number format of a number property schema
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum NumberFormat {
+    #[default]
+    Number,
+    NumberWithCommas,
+    /// `0.25` is shown as `25%`
+    Percent,
+    /// `dollar`, `won`, `euro`, ...
+    Currency(String),
+}
+
+/// (format, symbol, decimals)
+const CURRENCIES: [(&str, &str, usize); 37] = [
+    ("dollar", "$", 2),
+    ("canadian_dollar", "CA$", 2),
+    ("australian_dollar", "A$", 2),
+    ("singapore_dollar", "S$", 2),
+    ("hong_kong_dollar", "HK$", 2),
+    ("new_zealand_dollar", "NZ$", 2),
+    ("new_taiwan_dollar", "NT$", 2),
+    ("euro", "€", 2),
+    ("pound", "£", 2),
+    ("yen", "¥", 0),
+    ("won", "₩", 0),
+    ("yuan", "CN¥", 2),
+    ("ruble", "₽", 2),
+    ("rupee", "₹", 2),
+    ("rupiah", "Rp", 0),
+    ("real", "R$", 2),
+    ("lira", "₺", 2),
+    ("franc", "CHF ", 2),
+    ("krona", "kr ", 2),
+    ("norwegian_krone", "kr ", 2),
+    ("danish_krone", "kr ", 2),
+    ("zloty", "zł ", 2),
+    ("forint", "Ft ", 0),
+    ("koruna", "Kč ", 2),
+    ("baht", "฿", 2),
+    ("shekel", "₪", 2),
+    ("dirham", "AED ", 2),
+    ("riyal", "SAR ", 2),
+    ("ringgit", "RM", 2),
+    ("leu", "lei ", 2),
+    ("rand", "R", 2),
+    ("mexican_peso", "MX$", 2),
+    ("chilean_peso", "CLP$", 0),
+    ("colombian_peso", "COP$", 0),
+    ("argentine_peso", "ARS$", 2),
+    ("uruguayan_peso", "UYU$", 2),
+    ("philippine_peso", "₱", 2),
+];
+
+impl NumberFormat {
+    /// Unrecognized formats are treated as currencies
+    pub fn parse(format: &str) -> Self {
+        match format {
+            "number" | "" => NumberFormat::Number,
+            "number_with_commas" => NumberFormat::NumberWithCommas,
+            "percent" => NumberFormat::Percent,
+            currency => NumberFormat::Currency(currency.into()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            NumberFormat::Number => "number",
+            NumberFormat::NumberWithCommas => "number_with_commas",
+            NumberFormat::Percent => "percent",
+            NumberFormat::Currency(currency) => currency,
+        }
+    }
+
+    /// * `1234.5` - `1234.5`, `1,234.5`, `123,450%`, `$1,234.50`, `₩1,235`
+    pub fn format(&self, number: f64) -> String {
+        match self {
+            NumberFormat::Number => number.to_string(),
+            NumberFormat::NumberWithCommas => group_thousands(&number.to_string()),
+            NumberFormat::Percent => {
+                format!("{}%", group_thousands(&trim_fraction(number * 100.0)))
+            }
+            NumberFormat::Currency(currency) => {
+                let Some((_, symbol, decimals)) = CURRENCIES.iter().find(|(c, ..)| c == currency)
+                else {
+                    return group_thousands(&number.to_string());
+                };
+                let digits = group_thousands(&format!("{:.*}", decimals, number.abs()));
+                let sign = if number < 0.0 { "-" } else { "" };
+                format!("{}{}{}", sign, symbol, digits)
+            }
+        }
+    }
+}
+
+/// Drops float noise such as `7.000000000000001`
+fn trim_fraction(number: f64) -> String {
+    let fixed = format!("{:.6}", number);
+    fixed
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn group_thousands(number: &str) -> String {
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+
+    let mut grouped = String::new();
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+
+    match fraction {
+        Some(fraction) => format!("{}{}.{}", sign, grouped, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// `2025-11-27 21:00`
+    #[default]
+    Iso,
+    /// `Nov 27, 2025 9:00 PM`
+    En,
+    /// `2025년 11월 27일 오후 9:00`
+    Ko,
+}
+
+impl Locale {
+    fn format_date(&self, date: &NaiveDate) -> String {
+        match self {
+            Locale::Iso => date.format("%Y-%m-%d").to_string(),
+            Locale::En => date.format("%b %-d, %Y").to_string(),
+            Locale::Ko => date.format("%Y년 %-m월 %-d일").to_string(),
+        }
+    }
+
+    fn format_datetime(&self, datetime: &NaiveDateTime) -> String {
+        let date = self.format_date(&datetime.date());
+        match self {
+            Locale::Iso => format!("{} {}", date, datetime.format("%H:%M")),
+            Locale::En => format!("{} {}", date, datetime.format("%-I:%M %p")),
+            Locale::Ko => {
+                let meridiem = if datetime.hour() < 12 {
+                    "오전"
+                } else {
+                    "오후"
+                };
+                format!("{} {} {}", date, meridiem, datetime.format("%-I:%M"))
+            }
+        }
+    }
+
+    fn format_bool(&self, value: bool) -> &'static str {
+        match (self, value) {
+            (Locale::Iso, true) => "true",
+            (Locale::Iso, false) => "false",
+            (Locale::En, true) => "Yes",
+            (Locale::En, false) => "No",
+            (Locale::Ko, true) => "예",
+            (Locale::Ko, false) => "아니요",
+        }
+    }
+}
+
+/// Zone dates with a time are shown in
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DisplayZone {
+    Offset(FixedOffset),
+    /// IANA zone, follows daylight saving time
+    Named(Tz),
+}
+
+impl DisplayZone {
+    fn to_local<T: TimeZone>(self, datetime: &DateTime<T>) -> NaiveDateTime {
+        match self {
+            DisplayZone::Offset(offset) => datetime.with_timezone(&offset).naive_local(),
+            DisplayZone::Named(tz) => datetime.with_timezone(&tz).naive_local(),
+        }
+    }
+}
+
+/// Human-readable rendering of property values for reports
+///
+/// ```ignore
+/// let renderer = Renderer::new()
+///     .with_time_zone("Asia/Seoul".parse::<Tz>().unwrap())
+///     .with_locale(Locale::Ko);
+/// let schema = data_source.get_data()?.get_properties()?;
+/// for (name, value) in renderer.render_page(&page, &schema)? { ... }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Renderer {
+    zone: DisplayZone,
+    locale: Locale,
+    user_names: HashMap<UserId, String>,
+    page_titles: HashMap<PageId, String>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer {
+    /// UTC, ISO dates
+    pub fn new() -> Self {
+        Self {
+            zone: DisplayZone::Named(Tz::UTC),
+            locale: Locale::default(),
+            user_names: HashMap::new(),
+            page_titles: HashMap::new(),
+        }
+    }
+
+    /// Zone that timestamps and dates with a time are shown in
+    pub fn with_time_zone(mut self, time_zone: Tz) -> Self {
+        self.zone = DisplayZone::Named(time_zone);
+        self
+    }
+
+    /// Fixed offset without daylight saving time, prefer `with_time_zone`
+    pub fn with_offset(mut self, offset: FixedOffset) -> Self {
+        self.zone = DisplayZone::Offset(offset);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Name of users that only come with an ID, e.g. `created_by`
+    pub fn with_user_name(mut self, user_id: &UserId, name: &str) -> Self {
+        self.user_names.insert(user_id.clone(), name.into());
+        self
+    }
+
+    /// Title shown for related pages instead of their ID
+    pub fn with_page_title(mut self, page_id: &PageId, title: &str) -> Self {
+        self.page_titles.insert(page_id.clone(), title.into());
+        self
+    }
+
+    /// * `info` - Schema of the property, its number format is used for numbers
+    pub fn render(&self, value: &PropertyValue, info: Option<&PropertyInfo>) -> String {
+        let format = match info {
+            Some(PropertyInfo::Number { format }) => format,
+            _ => &NumberFormat::Number,
+        };
+
+        match value {
+            PropertyValue::UniqueId(id) => id.to_string(),
+            PropertyValue::Title(texts) | PropertyValue::RichText(texts) => to_plain_text(texts),
+            PropertyValue::Number(n) => n.map(|n| format.format(n)).unwrap_or_default(),
+            PropertyValue::Checkbox(v) => self.locale.format_bool(*v).into(),
+            PropertyValue::Date(date) => self.render_optional_date(date.as_ref()),
+            PropertyValue::Select(option) | PropertyValue::Status(option) => option
+                .as_ref()
+                .map(|o| o.get_name().to_string())
+                .unwrap_or_default(),
+            PropertyValue::MultiSelect(options) => {
+                join(options.iter().map(|o| o.get_name().to_string()))
+            }
+            PropertyValue::Url(v) | PropertyValue::Email(v) | PropertyValue::PhoneNumber(v) => {
+                v.clone().unwrap_or_default()
+            }
+            PropertyValue::Place(place) => place
+                .as_ref()
+                .map(|p| match p.get_name().or(p.get_address()) {
+                    Some(name) => name.to_string(),
+                    None => format!("{}, {}", p.get_lat(), p.get_lon()),
+                })
+                .unwrap_or_default(),
+            PropertyValue::People(users) => join(users.iter().map(|u| self.render_user(u))),
+            PropertyValue::Files(files) => join(files.iter().map(|f| f.get_name().to_string())),
+            PropertyValue::Formula(formula) => match formula {
+                FormulaValue::String { string } => string.clone().unwrap_or_default(),
+                FormulaValue::Number { number } => {
+                    number.map(|n| format.format(n)).unwrap_or_default()
+                }
+                FormulaValue::Boolean { boolean } => boolean
+                    .map(|b| self.locale.format_bool(b).to_string())
+                    .unwrap_or_default(),
+                FormulaValue::Date { date } => self.render_optional_date(date.as_ref()),
+            },
+            PropertyValue::Relation(relation) => join(relation.get_pages().iter().map(|id| {
+                self.page_titles
+                    .get(id)
+                    .cloned()
+                    .unwrap_or_else(|| id.to_string())
+            })),
+            PropertyValue::Rollup(rollup) => match rollup.get_result() {
+                RollupResult::Number(n) => n.map(|n| format.format(n)).unwrap_or_default(),
+                RollupResult::Date(date) => self.render_optional_date(date.as_ref()),
+                RollupResult::Array(items) => {
+                    join(items.iter().map(|item| self.render(item, None)))
+                }
+                RollupResult::Incomplete | RollupResult::Unsupported(_) => "".into(),
+            },
+            PropertyValue::Verification(v) => match (v.get_state(), v.get_expiry()) {
+                (VerificationState::Verified, Some(expiry)) => format!(
+                    "{} ({})",
+                    v.get_state().as_str(),
+                    self.render_timestamp(&expiry)
+                ),
+                (state, _) => state.as_str().into(),
+            },
+            PropertyValue::CreatedTime(t) | PropertyValue::LastEditedTime(t) => {
+                self.render_timestamp(t)
+            }
+            PropertyValue::CreatedBy(u) | PropertyValue::LastEditedBy(u) => self.render_user(u),
+            PropertyValue::Timestamp => "".into(),
+            PropertyValue::Unknown { .. } => value.get_value(),
+        }
+    }
+
+    /// (name, rendered value) of every page property
+    ///
+    /// * `schema` - Properties of the parent data source, see `GetDataSourceResponse::get_properties`
+    pub fn render_page(
+        &self,
+        page: &Page,
+        schema: &[SchemaProperty],
+    ) -> ClientResult<Vec<(String, String)>> {
+        Ok(page
+            .get_properties()?
+            .iter()
+            .map(|property| {
+                let info = schema
+                    .iter()
+                    .find(|s| s.get_id() == property.get_id())
+                    .map(|s| s.get_property_info());
+                (
+                    property.get_name().to_string(),
+                    self.render(property.get_property_value(), info),
+                )
+            })
+            .collect())
+    }
+
+    /// Ranges are joined with ` → `
+    pub fn render_date(&self, date: &DateValue) -> String {
+        let time_zone = date.get_time_zone().and_then(|tz| tz.parse::<Tz>().ok());
+
+        match date.get_end() {
+            Some(end) => format!(
+                "{} → {}",
+                self.render_date_time(date.get_start(), time_zone),
+                self.render_date_time(end, time_zone)
+            ),
+            None => self.render_date_time(date.get_start(), time_zone),
+        }
+    }
+
+    pub fn render_timestamp(&self, timestamp: &DateTime<Utc>) -> String {
+        self.locale.format_datetime(&self.zone.to_local(timestamp))
+    }
+
+    fn render_optional_date(&self, date: Option<&DateValue>) -> String {
+        date.map(|d| self.render_date(d)).unwrap_or_default()
+    }
+
+    /// * `time_zone` - Zone of the date value, floating values are local to it.
+    ///   Without one they are shown as is.
+    fn render_date_time(&self, value: &DateTimeValue, time_zone: Option<Tz>) -> String {
+        match value {
+            DateTimeValue::Date(date) => self.locale.format_date(date),
+            DateTimeValue::DateTime(datetime) => {
+                self.locale.format_datetime(&self.zone.to_local(datetime))
+            }
+            DateTimeValue::Floating(datetime) => {
+                let instant = time_zone.and_then(|tz| tz.from_local_datetime(datetime).earliest());
+                match instant {
+                    Some(instant) => self.locale.format_datetime(&self.zone.to_local(&instant)),
+                    None => self.locale.format_datetime(datetime),
+                }
+            }
+        }
+    }
+
+    fn render_user(&self, user: &User) -> String {
+        match user.get_name() {
+            Some(name) => name.into(),
+            None => self
+                .user_names
+                .get(user.get_id())
+                .cloned()
+                .unwrap_or_else(|| user.get_id().to_string()),
+        }
+    }
+}
+
+fn join(values: impl Iterator<Item = String>) -> String {
+    values.collect::<Vec<String>>().join(", ")
+}
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::UnknownObject;
+
+/// Entry of a files property
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct FileObject {
+    #[serde(default)]
+    name: String,
+    #[serde(flatten)]
+    source: FileSource,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FileSource {
+    /// Hosted by Notion, the signed `url` stops working at `expiry_time`
+    File {
+        file: HostedFile,
+    },
+    External {
+        external: ExternalFile,
+    },
+    /// Uploaded through the File Upload API, only used when writing
+    FileUpload {
+        file_upload: FileUploadRef,
+    },
+    /// Source added to Notion after this crate, written back unchanged
+    #[serde(untagged)]
+    Unknown(UnknownObject),
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct HostedFile {
+    url: String,
+    #[serde(skip_serializing)]
+    expiry_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ExternalFile {
+    url: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct FileUploadRef {
+    id: String,
+}
+
+impl FileObject {
+    pub fn external(name: &str, url: &str) -> Self {
+        Self {
+            name: name.into(),
+            source: FileSource::External {
+                external: ExternalFile { url: url.into() },
+            },
+        }
+    }
+
+    /// * `id` - ID of a completed file upload
+    pub fn file_upload(name: &str, id: &str) -> Self {
+        Self {
+            name: name.into(),
+            source: FileSource::FileUpload {
+                file_upload: FileUploadRef { id: id.into() },
+            },
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_source(&self) -> &FileSource {
+        &self.source
+    }
+
+    /// Download URL, `None` for file uploads and unknown sources
+    pub fn get_url(&self) -> Option<&str> {
+        self.source.get_url()
+    }
+
+    pub fn get_expiry_time(&self) -> Option<&DateTime<Utc>> {
+        match &self.source {
+            FileSource::File { file } => file.expiry_time.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Hosted file whose signed URL has expired, refetch the page to renew it
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.get_expiry_time().is_some_and(|expiry| *expiry <= now)
+    }
+
+    /// Same file, ignoring the signature of hosted file URLs
+    pub fn is_same(&self, other: &FileObject) -> bool {
+        self.name == other.name && self.source.is_same(&other.source)
+    }
+}
+
+impl FileSource {
+    pub fn get_url(&self) -> Option<&str> {
+        match self {
+            FileSource::File { file } => Some(&file.url),
+            FileSource::External { external } => Some(&external.url),
+            FileSource::FileUpload { .. } | FileSource::Unknown(_) => None,
+        }
+    }
+
+    /// Hosted URLs are re-signed on every fetch, so only their path is compared
+    pub fn is_same(&self, other: &FileSource) -> bool {
+        match (self, other) {
+            (FileSource::File { file }, FileSource::File { file: other }) => file.is_same(other),
+            _ => self == other,
+        }
+    }
+}
+
+impl HostedFile {
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn get_expiry_time(&self) -> Option<&DateTime<Utc>> {
+        self.expiry_time.as_ref()
+    }
+
+    pub fn is_same(&self, other: &HostedFile) -> bool {
+        let unsigned = |url: &str| url.split('?').next().unwrap_or_default().to_string();
+        unsigned(&self.url) == unsigned(&other.url)
+    }
+}
+
+impl ExternalFile {
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+}
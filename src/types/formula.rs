@@ -0,0 +1,68 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::date::DateValue;
+
+/// Result of a formula property. Every kind is `None` when the formula evaluates to empty.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FormulaValue {
+    String { string: Option<String> },
+    Number { number: Option<f64> },
+    Boolean { boolean: Option<bool> },
+    Date { date: Option<DateValue> },
+}
+
+impl FormulaValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FormulaValue::String { string } => string.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// `toNumber(dateBetween(...))` and other numeric formulas
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            FormulaValue::Number { number } => *number,
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FormulaValue::Boolean { boolean } => *boolean,
+            _ => None,
+        }
+    }
+
+    pub fn as_date(&self) -> Option<&DateValue> {
+        match self {
+            FormulaValue::Date { date } => date.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        match self {
+            FormulaValue::String { string } => string.is_none(),
+            FormulaValue::Number { number } => number.is_none(),
+            FormulaValue::Boolean { boolean } => boolean.is_none(),
+            FormulaValue::Date { date } => date.is_none(),
+        }
+    }
+}
+
+/// Empty string for null results
+impl fmt::Display for FormulaValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormulaValue::String { string } => write!(f, "{}", string.as_deref().unwrap_or("")),
+            FormulaValue::Number { number: Some(n) } => write!(f, "{}", n),
+            FormulaValue::Boolean { boolean: Some(b) } => write!(f, "{}", b),
+            FormulaValue::Date { date: Some(d) } => write!(f, "{}", d),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,675 @@
+use std::mem::discriminant;
+
+use chrono::{DateTime, Utc};
+use percent_encoding::{NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, de::DeserializeOwned, ser};
+use serde_json::{Value, json};
+
+use crate::{
+    errors::ClientError,
+    types::{
+        date::DateValue,
+        file::{ExternalFile, FileObject, FileSource, HostedFile},
+        formula::FormulaValue,
+        place::PlaceValue,
+        relation::{RELATION_WRITE_LIMIT, RelationEntry, RelationValue},
+        rich_text::{RichText, to_plain_text},
+        rollup::RollupValue,
+        select::SelectOption,
+        unique_id::UniqueIdValue,
+        user::User,
+        verification::{VerificationState, VerificationValue},
+    },
+};
+
+pub mod date;
+pub mod display;
+pub mod file;
+pub mod formula;
+pub mod place;
+pub mod relation;
+pub mod rich_text;
+pub mod rollup;
+pub mod select;
+pub mod unique_id;
+pub mod user;
+pub mod verification;
+
+pub type ClientResult<T> = Result<T, ClientError>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Method {
+    Get,
+    Post,
+    Patch,
+    Delete,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NotionResponse<T> {
+    object: String,
+    request_id: String,
+    #[serde(flatten)]
+    data: Option<T>,
+}
+
+impl<T> NotionResponse<T> {
+    /// `InvalidResponseError` when the body didn't match `T`
+    pub fn get_data(&self) -> ClientResult<&T> {
+        self.data.as_ref().ok_or_else(missing_data)
+    }
+
+    pub fn into_data(self) -> ClientResult<T> {
+        self.data.ok_or_else(missing_data)
+    }
+}
+
+impl<T: DeserializeOwned> NotionResponse<T> {
+    /// Unlike deserializing with the flattened `data`, reports why the body doesn't match `T`
+    pub(crate) fn from_value(value: Value) -> ClientResult<Self> {
+        let invalid = |e: serde_json::Error| ClientError::InvalidResponseError(e.to_string());
+        let field = |name: &str| value[name].as_str().unwrap_or_default().to_string();
+
+        Ok(NotionResponse {
+            object: field("object"),
+            request_id: field("request_id"),
+            data: Some(serde_json::from_value(value).map_err(invalid)?),
+        })
+    }
+}
+
+fn missing_data() -> ClientError {
+    ClientError::InvalidResponseError("response has no data".into())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ErrorResponse {
+    status: u16,
+    code: String,
+    object: String,
+    message: String,
+}
+
+impl ErrorResponse {
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Cover {
+    #[serde(flatten)]
+    source: FileSource,
+}
+
+impl Cover {
+    pub fn get_source(&self) -> &FileSource {
+        &self.source
+    }
+
+    pub fn get_url(&self) -> Option<&str> {
+        self.source.get_url()
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Icon {
+    Emoji {
+        emoji: String,
+    },
+    External {
+        external: ExternalFile,
+    },
+    File {
+        file: HostedFile,
+    },
+    CustomEmoji {
+        custom_emoji: CustomEmoji,
+    },
+    /// Icon type added to Notion after this crate
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CustomEmoji {
+    id: String,
+    name: String,
+    url: String,
+}
+
+impl Icon {
+    pub fn emoji(emoji: &str) -> Self {
+        Icon::Emoji {
+            emoji: emoji.into(),
+        }
+    }
+
+    pub fn get_emoji(&self) -> Option<&str> {
+        match self {
+            Icon::Emoji { emoji } => Some(emoji),
+            _ => None,
+        }
+    }
+
+    pub fn get_url(&self) -> Option<&str> {
+        match self {
+            Icon::External { external } => Some(external.get_url()),
+            Icon::File { file } => Some(file.get_url()),
+            Icon::CustomEmoji { custom_emoji } => Some(&custom_emoji.url),
+            _ => None,
+        }
+    }
+
+    /// Same icon, ignoring the signature of hosted file URLs
+    pub fn is_same(&self, other: &Icon) -> bool {
+        match (self, other) {
+            (Icon::File { file }, Icon::File { file: other }) => file.is_same(other),
+            _ => self == other,
+        }
+    }
+}
+
+/// Object of a type this crate doesn't model, kept as is to write it back unchanged
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(transparent)]
+pub struct UnknownObject(Value);
+
+impl UnknownObject {
+    pub fn get_type(&self) -> &str {
+        self.0["type"].as_str().unwrap_or_default()
+    }
+
+    pub fn get_raw(&self) -> &Value {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        match value["type"] {
+            Value::String(_) => Ok(UnknownObject(value)),
+            _ => Err(de::Error::missing_field("type")),
+        }
+    }
+}
+
+/// Property IDs arrive URL-encoded (`%5ETuB`), stored decoded (`^TuB`)
+pub fn decode_property_id(id: &str) -> String {
+    percent_decode_str(id).decode_utf8_lossy().into_owned()
+}
+
+/// Decoded property ID as a URL path or query segment
+pub fn encode_property_id(id: &str) -> String {
+    utf8_percent_encode(id, NON_ALPHANUMERIC).to_string()
+}
+
+/// Property addressed by name or by ID. IDs survive renames in Notion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PropertyRef {
+    Name(String),
+    Id(String),
+}
+
+impl PropertyRef {
+    pub fn name(name: &str) -> Self {
+        PropertyRef::Name(name.into())
+    }
+
+    /// * `id` - decoded or URL-encoded property ID
+    pub fn id(id: &str) -> Self {
+        PropertyRef::Id(decode_property_id(id))
+    }
+
+    pub fn matches(&self, id: &str, name: &str) -> bool {
+        match self {
+            PropertyRef::Name(n) => n == name,
+            PropertyRef::Id(i) => i == id,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            PropertyRef::Name(s) | PropertyRef::Id(s) => s,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Property {
+    id: String,
+    name: String,
+    property_value: PropertyValue,
+}
+
+impl Property {
+    pub fn new(name: &str, value: &Value) -> ClientResult<Self> {
+        let property_value: PropertyValue = value.try_into()?;
+
+        Ok(Property {
+            id: decode_property_id(value["id"].as_str().unwrap_or_default()),
+            name: name.into(),
+            property_value,
+        })
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is(&self, property: &PropertyRef) -> bool {
+        property.matches(&self.id, &self.name)
+    }
+
+    pub fn get_property_value(&self) -> &PropertyValue {
+        &self.property_value
+    }
+}
+
+/// Deserializes from the page object shape (`{"type": "number", "number": 3}`) and
+/// serializes to the create/update payload (`{"number": 3}`)
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(try_from = "Value")]
+pub enum PropertyValue {
+    UniqueId(UniqueIdValue),
+    Verification(VerificationValue),
+    Title(Vec<RichText>),
+    RichText(Vec<RichText>),
+
+    Number(Option<f64>),
+    Checkbox(bool),
+    /// `None` when the property is empty
+    Date(Option<DateValue>),
+    Timestamp,
+
+    Status(Option<SelectOption>),
+    Select(Option<SelectOption>),
+    MultiSelect(Vec<SelectOption>),
+
+    Url(Option<String>),
+    Email(Option<String>),
+    Place(Option<PlaceValue>),
+    People(Vec<User>),
+    PhoneNumber(Option<String>),
+    Files(Vec<FileObject>),
+
+    Formula(FormulaValue),
+    Relation(RelationValue),
+    Rollup(RollupValue),
+
+    CreatedTime(DateTime<Utc>),
+    CreatedBy(User),
+    LastEditedTime(DateTime<Utc>),
+    LastEditedBy(User),
+
+    /// Type added to Notion after this crate, written back unchanged
+    Unknown {
+        type_name: String,
+        raw: Value,
+    },
+}
+
+/// Every property type the API currently documents
+pub const PROPERTY_TYPES: [&str; 24] = [
+    "title",
+    "rich_text",
+    "number",
+    "checkbox",
+    "date",
+    "status",
+    "select",
+    "multi_select",
+    "url",
+    "email",
+    "phone_number",
+    "people",
+    "files",
+    "relation",
+    "rollup",
+    "formula",
+    "created_time",
+    "created_by",
+    "last_edited_time",
+    "last_edited_by",
+    "unique_id",
+    "verification",
+    "place",
+    "button",
+];
+
+impl PropertyValue {
+    pub fn variant_eq(&self, other: &Self) -> bool {
+        discriminant(self) == discriminant(other)
+    }
+
+    pub fn get_value(&self) -> String {
+        match self {
+            PropertyValue::UniqueId(id) => id.to_string(),
+            PropertyValue::Title(texts) | PropertyValue::RichText(texts) => to_plain_text(texts),
+            PropertyValue::Number(n) => n.map(|n| n.to_string()).unwrap_or_default(),
+            PropertyValue::Date(date) => date.as_ref().map(|d| d.to_string()).unwrap_or_default(),
+            PropertyValue::Checkbox(v) => v.to_string(),
+            PropertyValue::MultiSelect(options) => options
+                .iter()
+                .map(|o| o.get_name())
+                .collect::<Vec<&str>>()
+                .join("|"),
+            PropertyValue::Select(option) | PropertyValue::Status(option) => option
+                .as_ref()
+                .map(|o| o.get_name().to_string())
+                .unwrap_or_default(),
+            PropertyValue::Url(v) | PropertyValue::Email(v) | PropertyValue::PhoneNumber(v) => {
+                v.clone().unwrap_or_default()
+            }
+            PropertyValue::People(users) => users
+                .iter()
+                .map(|u| u.get_display_name())
+                .collect::<Vec<String>>()
+                .join("|"),
+            PropertyValue::Files(files) => files
+                .iter()
+                .map(|f| f.get_name())
+                .collect::<Vec<&str>>()
+                .join("|"),
+            PropertyValue::Formula(v) => v.to_string(),
+            PropertyValue::Relation(relation) => relation
+                .get_pages()
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<String>>()
+                .join("|"),
+            PropertyValue::Rollup(rollup) => rollup.get_value(),
+            PropertyValue::Verification(v) => v.get_state().as_str().into(),
+            PropertyValue::Place(place) => place
+                .as_ref()
+                .map(|p| match p.get_name().or(p.get_address()) {
+                    Some(name) => name.to_string(),
+                    None => format!("{},{}", p.get_lat(), p.get_lon()),
+                })
+                .unwrap_or_default(),
+            PropertyValue::CreatedTime(t) | PropertyValue::LastEditedTime(t) => t.to_rfc3339(),
+            PropertyValue::CreatedBy(u) | PropertyValue::LastEditedBy(u) => u.get_display_name(),
+            PropertyValue::Timestamp => "".into(),
+            PropertyValue::Unknown { raw, .. } => match raw {
+                Value::Null => "".into(),
+                Value::String(s) => s.clone(),
+                raw => raw.to_string(),
+            },
+        }
+    }
+
+    /// Value that clears a property of the given type on update
+    pub fn empty(type_str: &str) -> ClientResult<Self> {
+        let value = match type_str {
+            "title" => PropertyValue::Title(Vec::new()),
+            "rich_text" => PropertyValue::RichText(Vec::new()),
+            "number" => PropertyValue::Number(None),
+            "date" => PropertyValue::Date(None),
+            "status" => PropertyValue::Status(None),
+            "select" => PropertyValue::Select(None),
+            "multi_select" => PropertyValue::MultiSelect(Vec::new()),
+            "url" => PropertyValue::Url(None),
+            "email" => PropertyValue::Email(None),
+            "phone_number" => PropertyValue::PhoneNumber(None),
+            "people" => PropertyValue::People(Vec::new()),
+            "files" => PropertyValue::Files(Vec::new()),
+            "relation" => PropertyValue::Relation(RelationValue::default()),
+            "checkbox" => PropertyValue::Checkbox(false),
+            "verification" => PropertyValue::Verification(VerificationValue::unverified()),
+            "place" => PropertyValue::Place(None),
+            _ if PROPERTY_TYPES.contains(&type_str) => {
+                return Err(ClientError::ReadOnlyPropertyError(type_str.into()));
+            }
+            _ => PropertyValue::Unknown {
+                type_name: type_str.into(),
+                raw: Value::Null,
+            },
+        };
+
+        Ok(value)
+    }
+
+    /// No value set: empty text or list, `null` number/date/select/url, unchecked box,
+    /// unverified page
+    pub fn is_empty(&self) -> bool {
+        match self {
+            PropertyValue::Title(v) | PropertyValue::RichText(v) => v.is_empty(),
+            PropertyValue::Number(v) => v.is_none(),
+            PropertyValue::Checkbox(v) => !v,
+            PropertyValue::Date(v) => v.is_none(),
+            PropertyValue::Status(v) | PropertyValue::Select(v) => v.is_none(),
+            PropertyValue::MultiSelect(v) => v.is_empty(),
+            PropertyValue::Url(v) | PropertyValue::Email(v) | PropertyValue::PhoneNumber(v) => {
+                v.as_deref().is_none_or(str::is_empty)
+            }
+            PropertyValue::People(v) => v.is_empty(),
+            PropertyValue::Files(v) => v.is_empty(),
+            PropertyValue::Relation(v) => v.get_pages().is_empty(),
+            PropertyValue::Formula(v) => v.is_null(),
+            PropertyValue::Rollup(v) => v.get_value().is_empty(),
+            PropertyValue::Verification(v) => v.get_state() == VerificationState::Unverified,
+            PropertyValue::Place(v) => v.is_none(),
+            PropertyValue::Unknown { raw, .. } => raw.is_null(),
+            _ => false,
+        }
+    }
+
+    /// Whether the value can be sent in a create/update payload
+    ///
+    /// Documented types without a variant (`button`) can't be written either,
+    /// only types added to Notion after this crate are passed through.
+    pub fn is_writable(&self) -> bool {
+        match self {
+            PropertyValue::UniqueId(_)
+            | PropertyValue::Timestamp
+            | PropertyValue::Formula(_)
+            | PropertyValue::Rollup(_)
+            | PropertyValue::CreatedTime(_)
+            | PropertyValue::CreatedBy(_)
+            | PropertyValue::LastEditedTime(_)
+            | PropertyValue::LastEditedBy(_) => false,
+            PropertyValue::Unknown { type_name, .. } => {
+                !PROPERTY_TYPES.contains(&type_name.as_str())
+            }
+            _ => true,
+        }
+    }
+
+    /// Property value object for `POST /pages` and `PATCH /pages/{id}`,
+    /// e.g. `{"select": {"name": "일정"}}`
+    pub fn to_write_value(&self) -> ClientResult<Value> {
+        if !self.is_writable() {
+            return Err(ClientError::ReadOnlyPropertyError(self.to_string()));
+        }
+        let payload = match self {
+            PropertyValue::Title(texts) | PropertyValue::RichText(texts) => json!(texts),
+            PropertyValue::Number(n) => json!(n),
+            PropertyValue::Checkbox(v) => json!(v),
+            PropertyValue::Date(date) => json!(date),
+            PropertyValue::Status(option) | PropertyValue::Select(option) => {
+                json!(option.as_ref().map(|o| o.to_write_value()))
+            }
+            PropertyValue::MultiSelect(options) => json!(
+                options
+                    .iter()
+                    .map(|o| o.to_write_value())
+                    .collect::<Vec<Value>>()
+            ),
+            PropertyValue::Url(v) | PropertyValue::Email(v) | PropertyValue::PhoneNumber(v) => {
+                json!(v)
+            }
+            PropertyValue::People(users) => json!(users),
+            PropertyValue::Files(files) => json!(files),
+            // writing the first 25 pages would unlink the rest
+            PropertyValue::Relation(relation) if relation.has_more() => {
+                return Err(ClientError::ValidationError(
+                    "relation has more pages, read it with get_relation first".into(),
+                ));
+            }
+            PropertyValue::Relation(relation)
+                if relation.get_pages().len() > RELATION_WRITE_LIMIT =>
+            {
+                return Err(ClientError::ValidationError(format!(
+                    "relation has {} pages, at most {} can be written in one request",
+                    relation.get_pages().len(),
+                    RELATION_WRITE_LIMIT
+                )));
+            }
+            PropertyValue::Relation(relation) => json!(
+                relation
+                    .get_pages()
+                    .iter()
+                    .map(|id| json!({"id": id}))
+                    .collect::<Vec<Value>>()
+            ),
+            PropertyValue::Verification(v) => v.to_write_value(),
+            PropertyValue::Place(place) => json!(place),
+            PropertyValue::Unknown { raw, .. } => raw.clone(),
+            _ => unreachable!("read-only values are rejected above"),
+        };
+
+        let mut map = serde_json::Map::new();
+        map.insert(self.to_string(), payload);
+        Ok(Value::Object(map))
+    }
+}
+
+impl Serialize for PropertyValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_write_value()
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// Payload of a known property type in the shape the API documents
+pub(crate) fn parse_payload<T: DeserializeOwned>(type_str: &str, value: &Value) -> ClientResult<T> {
+    serde_json::from_value(value.clone())
+        .map_err(|e| ClientError::InvalidPropertyError(format!("{}: {}", type_str, e)))
+}
+
+/// * `value` - `{"id": ..., "type": "number", "number": 3}`
+///
+/// Types this crate doesn't know yet become `Unknown` instead of failing
+impl TryFrom<&Value> for PropertyValue {
+    type Error = ClientError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let type_str = value["type"]
+            .as_str()
+            .ok_or_else(|| ClientError::InvalidPropertyError("missing type".into()))?;
+        let has_more = value["has_more"].as_bool().unwrap_or(false);
+        let value = match value.get(type_str) {
+            Some(value) => value,
+            None if !PROPERTY_TYPES.contains(&type_str) => &Value::Null,
+            None => {
+                return Err(ClientError::InvalidPropertyError(format!(
+                    "{}: missing value",
+                    type_str
+                )));
+            }
+        };
+        let string = |v: &Value| v.as_str().map(|s| s.to_string());
+        let rich_text = |v: &Value| -> ClientResult<Vec<RichText>> {
+            Ok(parse_payload::<Option<_>>(type_str, v)?.unwrap_or_default())
+        };
+
+        let property_value = match type_str {
+            "title" => PropertyValue::Title(rich_text(value)?),
+            "date" => PropertyValue::Date(parse_payload(type_str, value)?),
+            "checkbox" => PropertyValue::Checkbox(parse_payload(type_str, value)?),
+            "email" => PropertyValue::Email(string(value)),
+            "files" => PropertyValue::Files(
+                parse_payload::<Option<_>>(type_str, value)?.unwrap_or_default(),
+            ),
+            "unique_id" => PropertyValue::UniqueId(parse_payload(type_str, value)?),
+            "multi_select" => PropertyValue::MultiSelect(
+                parse_payload::<Option<_>>(type_str, value)?.unwrap_or_default(),
+            ),
+            "number" => PropertyValue::Number(parse_payload(type_str, value)?),
+            "people" => PropertyValue::People(
+                parse_payload::<Option<_>>(type_str, value)?.unwrap_or_default(),
+            ),
+            "phone_number" => PropertyValue::PhoneNumber(string(value)),
+            "relation" => PropertyValue::Relation(
+                RelationValue::from(
+                    parse_payload::<Option<Vec<RelationEntry>>>(type_str, value)?
+                        .unwrap_or_default(),
+                )
+                .with_has_more(has_more),
+            ),
+            "rich_text" => PropertyValue::RichText(rich_text(value)?),
+            "select" => PropertyValue::Select(parse_payload(type_str, value)?),
+            "status" => PropertyValue::Status(parse_payload(type_str, value)?),
+            "timestamp" => PropertyValue::Timestamp,
+            "verification" => PropertyValue::Verification(
+                parse_payload::<Option<_>>(type_str, value)?
+                    .unwrap_or_else(VerificationValue::unverified),
+            ),
+            "place" => PropertyValue::Place(parse_payload(type_str, value)?),
+            "url" => PropertyValue::Url(string(value)),
+            "formula" => PropertyValue::Formula(parse_payload(type_str, value)?),
+            "rollup" => PropertyValue::Rollup(value.try_into()?),
+            "created_time" => PropertyValue::CreatedTime(parse_payload(type_str, value)?),
+            "created_by" => PropertyValue::CreatedBy(parse_payload(type_str, value)?),
+            "last_edited_time" => PropertyValue::LastEditedTime(parse_payload(type_str, value)?),
+            "last_edited_by" => PropertyValue::LastEditedBy(parse_payload(type_str, value)?),
+            _ => PropertyValue::Unknown {
+                type_name: type_str.into(),
+                raw: value.clone(),
+            },
+        };
+
+        Ok(property_value)
+    }
+}
+
+impl TryFrom<Value> for PropertyValue {
+    type Error = ClientError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        (&value).try_into()
+    }
+}
+
+impl ToString for &PropertyValue {
+    fn to_string(&self) -> String {
+        match self {
+            PropertyValue::UniqueId(_) => "unique_id",
+            PropertyValue::Title(_) => "title",
+            PropertyValue::Date(_) => "date",
+            PropertyValue::Checkbox(_) => "checkbox",
+            PropertyValue::Email(_) => "email",
+            PropertyValue::Files(_) => "files",
+            PropertyValue::MultiSelect(_) => "multi_select",
+            PropertyValue::Number(_) => "number",
+            PropertyValue::People(_) => "people",
+            PropertyValue::PhoneNumber(_) => "phone_number",
+            PropertyValue::Relation(_) => "relation",
+            PropertyValue::RichText(_) => "rich_text",
+            PropertyValue::Select(_) => "select",
+            PropertyValue::Status(_) => "status",
+            PropertyValue::Timestamp => "timestamp",
+            PropertyValue::Verification(_) => "verification",
+            PropertyValue::Place(_) => "place",
+            PropertyValue::Url(_) => "url",
+            PropertyValue::Formula(_) => "formula",
+            PropertyValue::Rollup(_) => "rollup",
+            PropertyValue::CreatedTime(_) => "created_time",
+            PropertyValue::CreatedBy(_) => "created_by",
+            PropertyValue::LastEditedTime(_) => "last_edited_time",
+            PropertyValue::LastEditedBy(_) => "last_edited_by",
+            PropertyValue::Unknown { type_name, .. } => type_name,
+        }
+        .into()
+    }
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Value of a place property
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct PlaceValue {
+    #[serde(alias = "latitude")]
+    lat: f64,
+    #[serde(alias = "longitude")]
+    lon: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    google_place_id: Option<String>,
+}
+
+impl PlaceValue {
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self {
+            lat,
+            lon,
+            name: None,
+            address: None,
+            google_place_id: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_address(mut self, address: &str) -> Self {
+        self.address = Some(address.into());
+        self
+    }
+
+    pub fn with_google_place_id(mut self, google_place_id: &str) -> Self {
+        self.google_place_id = Some(google_place_id.into());
+        self
+    }
+
+    pub fn get_lat(&self) -> f64 {
+        self.lat
+    }
+
+    pub fn get_lon(&self) -> f64 {
+        self.lon
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn get_address(&self) -> Option<&str> {
+        self.address.as_deref()
+    }
+
+    pub fn get_google_place_id(&self) -> Option<&str> {
+        self.google_place_id.as_deref()
+    }
+
+    /// Great-circle (haversine) distance in kilometers
+    pub fn distance_km(&self, other: &PlaceValue) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+
+    pub fn is_within_km(&self, center: &PlaceValue, radius_km: f64) -> bool {
+        self.distance_km(center) <= radius_km
+    }
+}
+
+/// Latitude/longitude rectangle, `min_lon > max_lon` crosses the antimeridian
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl BoundingBox {
+    pub fn new(min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) -> Self {
+        Self {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        }
+    }
+
+    /// Smallest box containing the circle of `radius_km` around `center`
+    pub fn around(center: &PlaceValue, radius_km: f64) -> Self {
+        let d_lat = (radius_km / EARTH_RADIUS_KM).to_degrees();
+        let d_lon = match center.lat.to_radians().cos() {
+            cos if cos > f64::EPSILON => (d_lat / cos).min(180.0),
+            _ => 180.0,
+        };
+        let wrap = |lon: f64| (lon + 540.0) % 360.0 - 180.0;
+
+        Self {
+            min_lat: (center.lat - d_lat).max(-90.0),
+            max_lat: (center.lat + d_lat).min(90.0),
+            min_lon: if d_lon >= 180.0 {
+                -180.0
+            } else {
+                wrap(center.lon - d_lon)
+            },
+            max_lon: if d_lon >= 180.0 {
+                180.0
+            } else {
+                wrap(center.lon + d_lon)
+            },
+        }
+    }
+
+    pub fn contains(&self, place: &PlaceValue) -> bool {
+        let in_lat = (self.min_lat..=self.max_lat).contains(&place.lat);
+        let in_lon = if self.min_lon <= self.max_lon {
+            (self.min_lon..=self.max_lon).contains(&place.lon)
+        } else {
+            place.lon >= self.min_lon || place.lon <= self.max_lon
+        };
+        in_lat && in_lon
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ids::PageId;
+
+/// Notion rejects create/update requests relating more pages than this
+pub const RELATION_WRITE_LIMIT: usize = 100;
+
+/// Related pages of a relation property
+///
+/// Page objects list at most 25 related pages and set `has_more` when there are
+/// more; `PageClient::get_relation` reads the complete list.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct RelationValue {
+    pages: Vec<PageId>,
+    has_more: bool,
+}
+
+impl RelationValue {
+    pub fn new(pages: Vec<PageId>) -> Self {
+        Self {
+            pages,
+            has_more: false,
+        }
+    }
+
+    pub(crate) fn with_has_more(mut self, has_more: bool) -> Self {
+        self.has_more = has_more;
+        self
+    }
+
+    pub fn get_pages(&self) -> &Vec<PageId> {
+        &self.pages
+    }
+
+    /// Only the first pages are listed
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    pub fn contains(&self, page_id: &PageId) -> bool {
+        self.pages.contains(page_id)
+    }
+
+    /// Appends pages that aren't related yet
+    pub fn add(&mut self, page_ids: &[PageId]) {
+        for page_id in page_ids {
+            if !self.contains(page_id) {
+                self.pages.push(page_id.clone());
+            }
+        }
+    }
+
+    pub fn remove(&mut self, page_ids: &[PageId]) {
+        self.pages.retain(|p| !page_ids.contains(p));
+    }
+}
+
+/// `{"id": ...}` entry of a relation payload
+#[derive(Deserialize)]
+pub(crate) struct RelationEntry {
+    id: PageId,
+}
+
+impl From<Vec<RelationEntry>> for RelationValue {
+    fn from(entries: Vec<RelationEntry>) -> Self {
+        Self::new(entries.into_iter().map(|entry| entry.id).collect())
+    }
+}
+
+impl From<Vec<PageId>> for RelationValue {
+    fn from(pages: Vec<PageId>) -> Self {
+        Self::new(pages)
+    }
+}
@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::ClientError,
+    ids::{DatabaseId, PageId, UserId},
+    types::{ClientResult, UnknownObject, date::DateValue, user::User},
+};
+
+/// One segment of a title, rich_text property, description or block
+///
+/// `plain_text` and `href` are filled in by Notion and left out of write payloads.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct RichText {
+    #[serde(flatten)]
+    content: RichTextContent,
+    #[serde(default)]
+    annotations: Annotations,
+    #[serde(default, skip_serializing)]
+    plain_text: String,
+    #[serde(default, skip_serializing)]
+    href: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichTextContent {
+    Text {
+        text: Text,
+    },
+    Mention {
+        mention: Mention,
+    },
+    Equation {
+        equation: Equation,
+    },
+    /// Type added to Notion after this crate
+    #[serde(untagged)]
+    Unknown(UnknownObject),
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Text {
+    content: String,
+    link: Option<Link>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Link {
+    url: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Equation {
+    expression: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Mention {
+    User {
+        user: User,
+    },
+    Page {
+        page: MentionId<PageId>,
+    },
+    Database {
+        database: MentionId<DatabaseId>,
+    },
+    Date {
+        date: DateValue,
+    },
+    LinkPreview {
+        link_preview: Link,
+    },
+    TemplateMention {
+        template_mention: TemplateMention,
+    },
+    /// `link_mention`, `custom_emoji` and other mentions this crate doesn't model, kept as is
+    #[serde(untagged)]
+    Unknown(UnknownObject),
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct MentionId<T> {
+    id: T,
+}
+
+/// Placeholders resolved when a template is used (`today`, `now`, `me`)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TemplateMention {
+    TemplateMentionDate { template_mention_date: String },
+    TemplateMentionUser { template_mention_user: String },
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Annotations {
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    underline: bool,
+    code: bool,
+    color: String,
+}
+
+impl Default for Annotations {
+    fn default() -> Self {
+        Self {
+            bold: false,
+            italic: false,
+            strikethrough: false,
+            underline: false,
+            code: false,
+            color: "default".into(),
+        }
+    }
+}
+
+impl Annotations {
+    pub fn is_bold(&self) -> bool {
+        self.bold
+    }
+
+    pub fn is_italic(&self) -> bool {
+        self.italic
+    }
+
+    pub fn is_strikethrough(&self) -> bool {
+        self.strikethrough
+    }
+
+    pub fn is_underline(&self) -> bool {
+        self.underline
+    }
+
+    pub fn is_code(&self) -> bool {
+        self.code
+    }
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+}
+
+impl RichText {
+    fn new(content: RichTextContent, plain_text: &str) -> Self {
+        Self {
+            content,
+            annotations: Annotations::default(),
+            plain_text: plain_text.into(),
+            href: None,
+        }
+    }
+
+    pub fn text(content: &str) -> Self {
+        Self::new(
+            RichTextContent::Text {
+                text: Text {
+                    content: content.into(),
+                    link: None,
+                },
+            },
+            content,
+        )
+    }
+
+    /// KaTeX expression
+    pub fn equation(expression: &str) -> Self {
+        Self::new(
+            RichTextContent::Equation {
+                equation: Equation {
+                    expression: expression.into(),
+                },
+            },
+            expression,
+        )
+    }
+
+    pub fn mention_page(id: &PageId) -> Self {
+        Self::mention(Mention::Page {
+            page: MentionId { id: id.clone() },
+        })
+    }
+
+    pub fn mention_database(id: &DatabaseId) -> Self {
+        Self::mention(Mention::Database {
+            database: MentionId { id: id.clone() },
+        })
+    }
+
+    pub fn mention_user(id: &UserId) -> Self {
+        Self::mention(Mention::User {
+            user: User::new(id),
+        })
+    }
+
+    pub fn mention_date(date: DateValue) -> Self {
+        Self::mention(Mention::Date { date })
+    }
+
+    pub fn mention(mention: Mention) -> Self {
+        Self::new(RichTextContent::Mention { mention }, "")
+    }
+
+    /// Only text segments can be linked, the API ignores `href` on writes
+    pub fn link(mut self, url: &str) -> ClientResult<Self> {
+        let RichTextContent::Text { text } = &mut self.content else {
+            return Err(ClientError::ValidationError(
+                "only text segments can carry a link".into(),
+            ));
+        };
+        text.link = Some(Link { url: url.into() });
+        self.href = Some(url.into());
+
+        Ok(self)
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.annotations.bold = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.annotations.italic = true;
+        self
+    }
+
+    pub fn strikethrough(mut self) -> Self {
+        self.annotations.strikethrough = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.annotations.underline = true;
+        self
+    }
+
+    pub fn code(mut self) -> Self {
+        self.annotations.code = true;
+        self
+    }
+
+    /// * `color` - e.g. `red`, `blue_background`
+    pub fn color(mut self, color: &str) -> Self {
+        self.annotations.color = color.into();
+        self
+    }
+
+    pub fn get_content(&self) -> &RichTextContent {
+        &self.content
+    }
+
+    pub fn get_annotations(&self) -> &Annotations {
+        &self.annotations
+    }
+
+    pub fn get_plain_text(&self) -> &str {
+        &self.plain_text
+    }
+
+    pub fn get_href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let text = match &self.content {
+            RichTextContent::Equation { equation } => return format!("${}$", equation.expression),
+            _ => self.plain_text.as_str(),
+        };
+
+        // markdown markers can't wrap surrounding whitespace
+        let body = text.trim();
+        if body.is_empty() {
+            return text.into();
+        }
+        let leading = &text[..text.len() - text.trim_start().len()];
+        let trailing = &text[text.trim_end().len()..];
+
+        let mut body = body.to_string();
+        if self.annotations.code {
+            body = format!("`{}`", body);
+        }
+        if self.annotations.italic {
+            body = format!("*{}*", body);
+        }
+        if self.annotations.bold {
+            body = format!("**{}**", body);
+        }
+        if self.annotations.strikethrough {
+            body = format!("~~{}~~", body);
+        }
+        if let Some(href) = &self.href {
+            body = format!("[{}]({})", body, href);
+        }
+
+        format!("{}{}{}", leading, body, trailing)
+    }
+}
+
+pub fn to_plain_text(texts: &[RichText]) -> String {
+    texts.iter().map(|t| t.get_plain_text()).collect()
+}
+
+pub fn to_markdown(texts: &[RichText]) -> String {
+    texts.iter().map(|t| t.to_markdown()).collect()
+}
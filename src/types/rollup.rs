@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    errors::ClientError,
+    types::{PropertyValue, date::DateValue, parse_payload},
+};
+
+/// Value of a rollup property with the aggregation that produced it
+///
+/// Read-only: deserializes from the API shape but has no write form
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(try_from = "Value")]
+pub struct RollupValue {
+    /// `show_original`, `count`, `sum`, `latest_date`, ...
+    function: String,
+    result: RollupResult,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RollupResult {
+    Number(Option<f64>),
+    Date(Option<DateValue>),
+    /// `show_original` / `show_unique`, one value per related page
+    Array(Vec<PropertyValue>),
+    /// Notion could not compute the whole rollup (too many related pages)
+    Incomplete,
+    /// Result type the API does not return, with the reported type name
+    Unsupported(String),
+}
+
+impl RollupValue {
+    pub fn get_function(&self) -> &str {
+        &self.function
+    }
+
+    pub fn get_result(&self) -> &RollupResult {
+        &self.result
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self.result {
+            RollupResult::Number(number) => number,
+            _ => None,
+        }
+    }
+
+    pub fn as_date(&self) -> Option<&DateValue> {
+        match &self.result {
+            RollupResult::Date(date) => date.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<PropertyValue>> {
+        match &self.result {
+            RollupResult::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !matches!(
+            self.result,
+            RollupResult::Incomplete | RollupResult::Unsupported(_)
+        )
+    }
+
+    pub fn get_value(&self) -> String {
+        match &self.result {
+            RollupResult::Number(number) => number.map(|n| n.to_string()).unwrap_or_default(),
+            RollupResult::Date(date) => date.as_ref().map(|d| d.to_string()).unwrap_or_default(),
+            RollupResult::Array(items) => items
+                .iter()
+                .map(|item| item.get_value())
+                .collect::<Vec<String>>()
+                .join("|"),
+            RollupResult::Incomplete | RollupResult::Unsupported(_) => "".into(),
+        }
+    }
+}
+
+/// * `value` - `{"type": "number", "number": 3, "function": "count"}`
+impl TryFrom<&Value> for RollupValue {
+    type Error = ClientError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let type_str = value["type"].as_str().unwrap_or_default();
+        let data = &value[type_str];
+
+        let result = match type_str {
+            "number" => RollupResult::Number(parse_payload(type_str, data)?),
+            "date" => RollupResult::Date(parse_payload(type_str, data)?),
+            "array" => RollupResult::Array(
+                data.as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|item| item.try_into())
+                    .collect::<Result<_, _>>()?,
+            ),
+            "incomplete" => RollupResult::Incomplete,
+            _ => RollupResult::Unsupported(type_str.into()),
+        };
+
+        Ok(Self {
+            function: value["function"].as_str().unwrap_or_default().into(),
+            result,
+        })
+    }
+}
+
+impl TryFrom<Value> for RollupValue {
+    type Error = ClientError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        (&value).try_into()
+    }
+}
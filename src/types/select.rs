@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Color of a select, multi_select or status option
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Color {
+    #[default]
+    Default,
+    Gray,
+    Brown,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Red,
+    /// Color added to Notion after this crate, never written back
+    #[serde(other)]
+    Unknown,
+}
+
+/// Option of a select, multi_select or status property
+///
+/// Options read from Notion carry their `id`, which is what gets written back,
+/// so a renamed option keeps matching.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct SelectOption {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(default)]
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl SelectOption {
+    /// Option matched by name, created by Notion if it doesn't exist yet
+    pub fn by_name(name: &str) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            color: None,
+            description: None,
+        }
+    }
+
+    pub fn by_id(id: &str) -> Self {
+        Self {
+            id: Some(id.into()),
+            name: String::new(),
+            color: None,
+            description: None,
+        }
+    }
+
+    /// Color of an option created by name
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn get_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_color(&self) -> Color {
+        self.color.unwrap_or_default()
+    }
+
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Matches either the option ID or its name
+    pub fn is(&self, id_or_name: &str) -> bool {
+        self.id.as_deref() == Some(id_or_name) || self.name == id_or_name
+    }
+
+    /// `{"id": ...}` when known, `{"name": ..., "color": ...}` otherwise
+    pub fn to_write_value(&self) -> Value {
+        match (&self.id, self.color) {
+            (Some(id), _) => json!({"id": id}),
+            (None, Some(color)) if color != Color::Unknown => {
+                json!({"name": self.name, "color": color})
+            }
+            (None, _) => json!({"name": self.name}),
+        }
+    }
+}
+
+impl From<&str> for SelectOption {
+    fn from(name: &str) -> Self {
+        Self::by_name(name)
+    }
+}
+
+/// Group of status options (`To-do`, `In progress`, `Complete`)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct StatusGroup {
+    id: String,
+    name: String,
+    #[serde(default)]
+    color: Color,
+    #[serde(default)]
+    option_ids: Vec<String>,
+}
+
+impl StatusGroup {
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    pub fn contains(&self, option: &SelectOption) -> bool {
+        option
+            .get_id()
+            .is_some_and(|id| self.option_ids.iter().any(|o| o == id))
+    }
+}
@@ -0,0 +1,64 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ClientError, types::ClientResult};
+
+/// Value of a unique_id property, shown by Notion as `TASK-42` (or `42` without a prefix)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct UniqueIdValue {
+    #[serde(default)]
+    prefix: Option<String>,
+    number: Option<u64>,
+}
+
+impl UniqueIdValue {
+    pub fn new(prefix: Option<&str>, number: u64) -> Self {
+        Self {
+            prefix: prefix.map(|p| p.into()),
+            number: Some(number),
+        }
+    }
+
+    /// * `value` - `TASK-42`, `42`
+    pub fn parse(value: &str) -> ClientResult<Self> {
+        let invalid = || ClientError::ValidationError(format!("Invalid unique ID: {}", value));
+        let value = value.trim();
+
+        let (prefix, number) = match value.rsplit_once('-') {
+            Some((prefix, number)) if !prefix.is_empty() => (Some(prefix), number),
+            Some(_) => return Err(invalid()),
+            None => (None, value),
+        };
+        let number = number.parse::<u64>().map_err(|_| invalid())?;
+
+        Ok(Self::new(prefix, number))
+    }
+
+    pub fn get_prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    pub fn get_number(&self) -> Option<u64> {
+        self.number
+    }
+}
+
+/// Empty string while Notion hasn't assigned a number
+impl fmt::Display for UniqueIdValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.prefix, self.number) {
+            (Some(prefix), Some(number)) => write!(f, "{}-{}", prefix, number),
+            (None, Some(number)) => write!(f, "{}", number),
+            (_, None) => Ok(()),
+        }
+    }
+}
+
+impl FromStr for UniqueIdValue {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ids::UserId;
+
+/// Person or bot. `created_by`/`last_edited_by` usually carry only the ID;
+/// people properties and `users` endpoints return the rest.
+///
+/// Only `object` and `id` are serialized, which is what write payloads accept.
+/// People properties may also list other objects with an ID (e.g. `group`), which
+/// keep their `object` and are written back as is.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct User {
+    #[serde(default = "user_object")]
+    object: String,
+    id: UserId,
+    #[serde(default, rename = "type", skip_serializing)]
+    user_type: Option<UserType>,
+    #[serde(default, skip_serializing)]
+    name: Option<String>,
+    #[serde(default, skip_serializing)]
+    avatar_url: Option<String>,
+    #[serde(default, skip_serializing)]
+    person: Option<Person>,
+    #[serde(default, skip_serializing)]
+    bot: Option<Bot>,
+}
+
+fn user_object() -> String {
+    "user".into()
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UserType {
+    Person,
+    Bot,
+    /// Type added to Notion after this crate
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Person {
+    #[serde(default)]
+    email: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Bot {
+    #[serde(default)]
+    owner: Option<BotOwner>,
+    #[serde(default)]
+    workspace_name: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotOwner {
+    Workspace { workspace: bool },
+    User { user: Box<User> },
+}
+
+impl User {
+    /// Reference by ID, e.g. for people property writes
+    pub fn new(id: &UserId) -> Self {
+        Self {
+            object: user_object(),
+            id: id.clone(),
+            user_type: None,
+            name: None,
+            avatar_url: None,
+            person: None,
+            bot: None,
+        }
+    }
+
+    pub fn get_id(&self) -> &UserId {
+        &self.id
+    }
+
+    /// `None` for partial users that only carry an ID
+    pub fn get_type(&self) -> Option<UserType> {
+        self.user_type
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn get_avatar_url(&self) -> Option<&str> {
+        self.avatar_url.as_deref()
+    }
+
+    /// Only visible to integrations with the user email capability
+    pub fn get_email(&self) -> Option<&str> {
+        self.person.as_ref()?.email.as_deref()
+    }
+
+    pub fn get_bot_owner(&self) -> Option<&BotOwner> {
+        self.bot.as_ref()?.owner.as_ref()
+    }
+
+    pub fn get_workspace_name(&self) -> Option<&str> {
+        self.bot.as_ref()?.workspace_name.as_deref()
+    }
+
+    pub fn is_person(&self) -> bool {
+        self.user_type == Some(UserType::Person)
+    }
+
+    pub fn is_bot(&self) -> bool {
+        self.user_type == Some(UserType::Bot)
+    }
+
+    /// Name when known, the ID otherwise
+    pub fn get_display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => self.id.to_string(),
+        }
+    }
+}
+
+impl From<&UserId> for User {
+    fn from(id: &UserId) -> Self {
+        Self::new(id)
+    }
+}
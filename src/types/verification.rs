@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::types::{date::DateValue, user::User};
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationState {
+    Verified,
+    #[default]
+    Unverified,
+    Expired,
+}
+
+impl VerificationState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerificationState::Verified => "verified",
+            VerificationState::Unverified => "unverified",
+            VerificationState::Expired => "expired",
+        }
+    }
+}
+
+/// Value of a verification property on a wiki page
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct VerificationValue {
+    #[serde(default)]
+    state: VerificationState,
+    #[serde(default)]
+    verified_by: Option<User>,
+    /// Start is when the page was verified, end when the verification expires
+    #[serde(default)]
+    date: Option<DateValue>,
+}
+
+impl VerificationValue {
+    /// * date - verification period, `None` verifies indefinitely
+    pub fn verified(date: Option<DateValue>) -> Self {
+        Self {
+            state: VerificationState::Verified,
+            verified_by: None,
+            date,
+        }
+    }
+
+    pub fn unverified() -> Self {
+        Self {
+            state: VerificationState::Unverified,
+            verified_by: None,
+            date: None,
+        }
+    }
+
+    pub fn get_state(&self) -> VerificationState {
+        self.state
+    }
+
+    pub fn get_verified_by(&self) -> Option<&User> {
+        self.verified_by.as_ref()
+    }
+
+    pub fn get_date(&self) -> Option<&DateValue> {
+        self.date.as_ref()
+    }
+
+    /// End of the verification period, date-only ends at midnight UTC
+    pub fn get_expiry(&self) -> Option<DateTime<Utc>> {
+        let end = self.date.as_ref()?.get_end()?;
+        end.to_datetime_in(&FixedOffset::east_opt(0)?)
+            .map(|end| end.to_utc())
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.state == VerificationState::Verified
+    }
+
+    /// Marked expired, or verified with a period that ended before `now`
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        match self.state {
+            VerificationState::Expired => true,
+            VerificationState::Verified => self.get_expiry().is_some_and(|end| end <= now),
+            VerificationState::Unverified => false,
+        }
+    }
+
+    /// Expired, or verified with a period ending within `within` from `now`
+    pub fn is_expiring(&self, now: DateTime<Utc>, within: Duration) -> bool {
+        self.is_expired(now)
+            || (self.is_verified() && self.get_expiry().is_some_and(|end| end <= now + within))
+    }
+
+    /// `verified_by` is set by Notion and left out
+    pub fn to_write_value(&self) -> Value {
+        match &self.date {
+            Some(date) => json!({"state": self.state, "date": date}),
+            None => json!({"state": self.state}),
+        }
+    }
+}
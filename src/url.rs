@@ -0,0 +1,158 @@
+use std::str::FromStr;
+
+use reqwest::Url;
+
+use crate::{
+    errors::ClientError,
+    ids::{BlockId, DataSourceId, DatabaseId, PageId, ViewId},
+    types::ClientResult,
+};
+
+const NOTION_HOSTS: [&str; 3] = ["notion.so", "notion.site", "notion.com"];
+
+/// Object a Notion link points to
+///
+/// A database link without `?v=` can't be told apart from a page link,
+/// so it is returned as `Page` (the API accepts it as a database ID too).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotionUrl {
+    Page(PageId),
+    /// `#block` anchor inside a page
+    Block {
+        page_id: PageId,
+        block_id: BlockId,
+    },
+    /// `?v=` view of a database
+    Database {
+        database_id: DatabaseId,
+        view_id: Option<ViewId>,
+    },
+    /// `?p=` page opened in peek mode on top of a database
+    PeekPage {
+        database_id: DatabaseId,
+        view_id: Option<ViewId>,
+        page_id: PageId,
+    },
+    /// `collection://` link copied from a data source
+    DataSource(DataSourceId),
+}
+
+impl NotionUrl {
+    /// * `value` - notion.so / notion.site / notion.com link, `collection://` data source
+    ///   link, or a page published on a custom domain whose path ends in a 32-hex ID
+    pub fn parse(value: &str) -> ClientResult<Self> {
+        let invalid =
+            |reason: &str| ClientError::InvalidUrlError(format!("{} ({})", reason, value));
+
+        let url = Url::parse(value.trim()).map_err(|_| invalid("not a URL"))?;
+
+        if url.scheme() == "collection" {
+            let id = url
+                .host_str()
+                .ok_or_else(|| invalid("missing data source ID"))?;
+            return DataSourceId::parse(id)
+                .map(NotionUrl::DataSource)
+                .map_err(|_| invalid("invalid data source ID"));
+        }
+
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(invalid("not a Notion link"));
+        }
+
+        let host = url.host_str().unwrap_or_default();
+        let is_notion_host = NOTION_HOSTS
+            .iter()
+            .any(|h| host == *h || host.ends_with(&format!(".{}", h)));
+        let segment = url
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|s| !s.is_empty()));
+
+        let path_id = if is_notion_host {
+            segment
+                .and_then(id_from_segment)
+                .ok_or_else(|| invalid("missing ID in path"))?
+        } else {
+            segment.and_then(published_id).ok_or_else(|| {
+                invalid("not a Notion link (custom domains need a 32-hex page ID in the path)")
+            })?
+        };
+
+        let query = |key: &str| {
+            url.query_pairs()
+                .find(|(k, v)| k == key && !v.is_empty())
+                .map(|(_, v)| v.into_owned())
+        };
+
+        let view_id = match query("v") {
+            Some(v) => Some(ViewId::parse(&v).map_err(|_| invalid("invalid view ID"))?),
+            None => None,
+        };
+
+        if let Some(p) = query("p") {
+            return Ok(NotionUrl::PeekPage {
+                database_id: DatabaseId::parse(path_id)?,
+                view_id,
+                page_id: PageId::parse(&p).map_err(|_| invalid("invalid peek page ID"))?,
+            });
+        }
+
+        if view_id.is_some() {
+            return Ok(NotionUrl::Database {
+                database_id: DatabaseId::parse(path_id)?,
+                view_id,
+            });
+        }
+
+        match url.fragment().filter(|f| !f.is_empty()) {
+            Some(fragment) => Ok(NotionUrl::Block {
+                page_id: PageId::parse(path_id)?,
+                block_id: BlockId::parse(fragment).map_err(|_| invalid("invalid block ID"))?,
+            }),
+            None => Ok(NotionUrl::Page(PageId::parse(path_id)?)),
+        }
+    }
+
+    /// Page the link opens, including peeked pages and pages holding a block
+    pub fn get_page_id(&self) -> Option<&PageId> {
+        match self {
+            NotionUrl::Page(page_id)
+            | NotionUrl::Block { page_id, .. }
+            | NotionUrl::PeekPage { page_id, .. } => Some(page_id),
+            _ => None,
+        }
+    }
+
+    pub fn get_database_id(&self) -> Option<&DatabaseId> {
+        match self {
+            NotionUrl::Database { database_id, .. } | NotionUrl::PeekPage { database_id, .. } => {
+                Some(database_id)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for NotionUrl {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Custom domain paths only count with an undashed ID, like Notion writes them
+fn published_id(segment: &str) -> Option<&str> {
+    let id = segment.get(segment.len().checked_sub(32)?..)?;
+    let prefix = &segment[..segment.len() - 32];
+
+    (id.bytes().all(|b| b.is_ascii_hexdigit()) && (prefix.is_empty() || prefix.ends_with('-')))
+        .then_some(id)
+}
+
+/// `Title-28714e4a515780cb8961000b4d54c831` or a bare (dashed) ID
+fn id_from_segment(segment: &str) -> Option<&str> {
+    [36, 32]
+        .into_iter()
+        .filter_map(|len| segment.get(segment.len().checked_sub(len)?..))
+        .find(|candidate| PageId::parse(candidate).is_ok())
+}
@@ -3,7 +3,15 @@ mod test {
     use std::env;
 
     use dotenv::dotenv;
-    use notion::{NotionAPI, data_source::DataSourceClient};
+    use notion::{
+        NotionAPI,
+        data_source::{
+            DataSourceClient, dto::response::GetDataSourceResponse, types::PropertyInfo,
+        },
+        errors::ClientError,
+        ids::DataSourceId,
+        types::{PropertyRef, select::Color},
+    };
     use serde_json::json;
 
     #[tokio::test]
@@ -14,7 +22,8 @@ mod test {
         let token = env::var("NOTION_KEY").expect("Failed to load env variable: NOTION_KEY");
         let api = api.authed(&token);
 
-        let result = api.get_data_source("{data_source_id}").await.unwrap();
+        let data_source_id = DataSourceId::parse("{data_source_id}").unwrap();
+        let result = api.get_data_source(&data_source_id).await.unwrap();
 
         println!("{:?}", result);
     }
@@ -27,10 +36,11 @@ mod test {
         let token = env::var("NOTION_KEY").expect("Failed to load env variable: NOTION_KEY");
         let api = api.authed(&token);
 
+        let data_source_id = DataSourceId::parse("{data_source_id}").unwrap();
         let result = api
             .query_pages(
-                "{data_source_id}",
-                vec!["title"],
+                &data_source_id,
+                vec![PropertyRef::id("title")],
                 json!({"and": [
                     {
                         "property": "날짜",
@@ -47,11 +57,149 @@ mod test {
                         }
                     }
                 ]}),
-                vec![("날짜", "asc")],
+                vec![(PropertyRef::name("날짜"), "asc")],
             )
             .await
             .unwrap();
 
         println!("{:?}", result);
     }
+
+    fn data_source_fixture() -> GetDataSourceResponse {
+        serde_json::from_value(json!({
+            "object": "data_source",
+            "id": "28714e4a-5157-80cb-8961-000b4d54c831",
+            "title": [
+                {"type": "text", "text": {"content": "할 일 ", "link": null}, "plain_text": "할 일 ", "href": null},
+                {"type": "mention", "mention": {"type": "link_mention", "link_mention": {"href": "https://github.com"}},
+                 "plain_text": "GitHub", "href": "https://github.com"}
+            ],
+            "archived": false,
+            "is_inline": false,
+            "cover": null,
+            "icon": null,
+            "parent": {"type": "database_id", "database_id": "28714e4a-5157-80d3-8986-d613367fa5a1"},
+            "database_parent": {"type": "page_id", "page_id": "28714e4a-5157-80f2-af8c-e83654e77692"},
+            "properties": {
+                "이름": {"id": "title", "name": "이름", "type": "title", "title": {}},
+                "날짜": {"id": "d%5Dl%5D", "name": "날짜", "type": "date", "date": {}},
+                "상태": {"id": "%5ETuB", "name": "상태", "type": "checkbox", "checkbox": {}},
+                "ID": {"id": "nd%3Fr", "name": "ID", "type": "unique_id", "unique_id": {"prefix": "TASK"}},
+                "구분": {"id": "Tz%7Cq", "name": "구분", "type": "select", "select": {"options": [
+                    {"color": "blue", "description": null, "id": "e8f65228-6110-4578-a56c-b2ef8330c27f", "name": "다음행동"},
+                    {"color": "yellow", "description": null, "id": "70e42a58-3f58-4c94-bd2d-03b5ff1f9fdd", "name": "일정"}
+                ]}},
+                "태그": {"id": "fW%3Bs", "name": "태그", "type": "multi_select", "multi_select": {"options": "broken"}}
+            },
+            "url": "https://www.notion.so/28714e4a515780cb8961000b4d54c831",
+            "created_time": "2025-11-07T00:00:00.000Z",
+            "last_edited_time": "2025-11-07T00:00:00.000Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_property_id_decoding() {
+        let data_source = data_source_fixture();
+
+        let status = data_source
+            .find_property(&PropertyRef::id("%5ETuB"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(status.get_id(), "^TuB");
+        assert_eq!(status.get_name(), "상태");
+
+        assert_eq!(
+            data_source
+                .resolve_property_id(&PropertyRef::name("날짜"))
+                .unwrap(),
+            "d]l]"
+        );
+        assert!(matches!(
+            data_source.resolve_property_id(&PropertyRef::name("없음")),
+            Err(ClientError::PropertyNotFoundError(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_filter() {
+        let data_source = data_source_fixture();
+
+        let filter = data_source
+            .resolve_filter(json!({"and": [
+                {"property": "날짜", "date": {"after": "2025-12-15T00:00:00+09:00"}},
+                {"property": "^TuB", "checkbox": {"equals": true}}
+            ]}))
+            .unwrap();
+
+        assert_eq!(
+            filter,
+            json!({"and": [
+                {"property": "d]l]", "date": {"after": "2025-12-15T00:00:00+09:00"}},
+                {"property": "^TuB", "checkbox": {"equals": true}}
+            ]})
+        );
+        assert!(
+            data_source
+                .resolve_filter(json!({"property": "없음", "checkbox": {"equals": true}}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_select_options() {
+        let data_source = data_source_fixture();
+        let category = data_source
+            .find_property(&PropertyRef::name("구분"))
+            .unwrap()
+            .unwrap();
+        let info = category.get_property_info();
+
+        assert_eq!(info.get_options().len(), 2);
+        let option = info.find_option("일정").unwrap();
+        assert_eq!(
+            option.get_id(),
+            Some("70e42a58-3f58-4c94-bd2d-03b5ff1f9fdd")
+        );
+        assert_eq!(option.get_color(), Color::Yellow);
+        assert_eq!(
+            info.find_option("e8f65228-6110-4578-a56c-b2ef8330c27f")
+                .unwrap()
+                .get_name(),
+            "다음행동"
+        );
+        assert!(info.find_option("없음").is_none());
+    }
+
+    #[test]
+    fn test_unique_id_schema() {
+        let data_source = data_source_fixture();
+        let id = data_source
+            .find_property(&PropertyRef::name("ID"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(id.get_id(), "nd?r");
+        assert!(matches!(
+            id.get_property_info(),
+            PropertyInfo::UniqueId { prefix: Some(prefix) } if prefix == "TASK"
+        ));
+        assert_eq!(id.get_property_info().to_string(), "unique_id");
+    }
+
+    #[test]
+    fn test_unreadable_property_config() {
+        let data_source = data_source_fixture();
+        assert_eq!(data_source.get_properties().unwrap().len(), 6);
+
+        let tags = data_source
+            .find_property(&PropertyRef::name("태그"))
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            tags.get_property_info(),
+            PropertyInfo::Unknown { type_name, raw }
+                if type_name == "multi_select" && raw["options"] == "broken"
+        ));
+    }
 }
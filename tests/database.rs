@@ -3,7 +3,7 @@ mod test {
     use std::env;
 
     use dotenv::dotenv;
-    use notion::{NotionAPI, database::DatabaseClient};
+    use notion::{NotionAPI, database::DatabaseClient, ids::DatabaseId};
 
     #[tokio::test]
     async fn test_get_db() {
@@ -13,7 +13,8 @@ mod test {
         let token = env::var("NOTION_KEY").expect("Failed to load env variable: NOTION_KEY");
         let api = api.authed(&token);
 
-        let result = api.get_database("{database_id}").await.unwrap();
+        let database_id = DatabaseId::parse("{database_id}").unwrap();
+        let result = api.get_database(&database_id).await.unwrap();
 
         println!("{:?}", result);
     }
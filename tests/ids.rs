@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod test {
+    use notion::{
+        errors::ClientError,
+        ids::{DataSourceId, PageId},
+        page::types::PageParent,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_id() {
+        let dashed = PageId::parse("28714e4a-5157-80cb-8961-000b4d54c831").unwrap();
+        let undashed = PageId::parse("28714E4A515780CB8961000B4D54C831").unwrap();
+
+        assert_eq!(dashed, undashed);
+        assert_eq!(undashed.as_str(), "28714e4a-5157-80cb-8961-000b4d54c831");
+        assert_eq!(dashed.to_simple(), "28714e4a515780cb8961000b4d54c831");
+    }
+
+    #[test]
+    fn test_invalid_id() {
+        assert!(matches!(
+            PageId::parse("{page_id}"),
+            Err(ClientError::InvalidIdError(_))
+        ));
+        assert!(PageId::parse("28714e4a515780cb8961000b4d54c8").is_err());
+        assert!(serde_json::from_value::<PageId>(json!("not-an-id")).is_err());
+    }
+
+    #[test]
+    fn test_serialize_parent() {
+        let id: DataSourceId = "28714e4a515780879cfd000bf0979848".parse().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(PageParent::new_datasource(&id)).unwrap(),
+            json!({"type": "data_source_id", "data_source_id": "28714e4a-5157-8087-9cfd-000bf0979848"})
+        );
+    }
+}
@@ -2,9 +2,44 @@
 mod test {
     use std::env;
 
+    use chrono::{Duration, NaiveDate};
     use dotenv::dotenv;
-    use notion::{NotionAPI, page::PageClient};
-    use serde_json::json;
+    use notion::{
+        NotionAPI,
+        errors::ClientError,
+        ids::{PageId, UserId},
+        page::{
+            PageClient,
+            diff::{ChangeKind, PageDiff},
+            dto::response::PropertyItemResponse,
+            types::{Page, PageProperties, find_stale_pages},
+        },
+        types::{PropertyValue, date::DateValue, verification::VerificationState},
+    };
+    use serde_json::{Value, json};
+
+    fn page_fixture(properties: Value) -> Page {
+        serde_json::from_value(page_json(properties)).unwrap()
+    }
+
+    fn page_json(properties: Value) -> Value {
+        json!({
+            "object": "page",
+            "id": "28714e4a-5157-80cb-8961-000b4d54c831",
+            "created_time": "2025-11-07T00:00:00.000Z",
+            "last_edited_time": "2025-11-07T00:00:00.000Z",
+            "created_by": {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"},
+            "last_edited_by": {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"},
+            "cover": null,
+            "icon": null,
+            "parent": {"type": "data_source_id", "data_source_id": "28714e4a-5157-8087-9cfd-000bf0979848"},
+            "archived": false,
+            "in_trash": false,
+            "properties": properties,
+            "url": "https://www.notion.so/28714e4a515780cb8961000b4d54c831",
+            "public_url": null
+        })
+    }
 
     #[tokio::test]
     async fn test_update_page() {
@@ -14,19 +49,348 @@ mod test {
         let token = env::var("NOTION_KEY").expect("Failed to load env variable: NOTION_KEY");
         let api = api.authed(&token);
 
-        let page_id = "{page_id}";
-        let properties = json!({
-            "날짜": {
-                "date": {
-                    "start": "2025-11-27",
-                    // "end": "2025-11-11T22:00:00+09:00",
-                }
-            }
-        });
-        // vec (property name, property type, value)
+        let page_id = PageId::parse("{page_id}").unwrap();
+        let properties =
+            PageProperties::new().date("날짜", NaiveDate::from_ymd_opt(2025, 11, 27).unwrap());
 
-        let result = api.update_page(page_id, properties).await.unwrap();
+        let result = api.update_page(&page_id, properties).await.unwrap();
 
         println!("{:?}", result);
     }
+
+    #[test]
+    fn test_page_users() {
+        let page = page_fixture(json!({}));
+        assert_eq!(
+            page.get_created_by().get_id(),
+            &UserId::parse("2d0d6924-cbad-43bd-87a5-cfcf7be2872b").unwrap()
+        );
+        assert_eq!(page.get_last_edited_by().get_name(), None);
+    }
+
+    #[test]
+    fn test_truncated_properties() {
+        let relations = (0..25)
+            .map(|i| json!({"id": format!("28714e4a-5157-80cb-8961-0000000000{:02}", i)}))
+            .collect::<Vec<Value>>();
+        let page = page_fixture(json!({
+            "할일PJT": {"id": "Upwn", "type": "relation", "relation": relations, "has_more": true},
+            "노트": {"id": "%3CO%7Cd", "type": "relation", "relation": [], "has_more": false},
+            "완료": {"id": "d%5Dl%5D", "type": "checkbox", "checkbox": true}
+        }));
+
+        assert_eq!(
+            page.get_truncated_properties(),
+            vec![("할일PJT".to_string(), "Upwn".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_property_item_list() {
+        let response: PropertyItemResponse = serde_json::from_value(json!({
+            "results": [
+                {"object": "property_item", "id": "Upwn", "type": "relation", "relation": {"id": "28714e4a-5157-80cb-8961-000000000001"}},
+                {"object": "property_item", "id": "Upwn", "type": "relation", "relation": {"id": "28714e4a-5157-80cb-8961-000000000002"}}
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": {"id": "Upwn", "next_url": null, "type": "relation", "relation": {}}
+        }))
+        .unwrap();
+
+        let PropertyItemResponse::List(list) = response else {
+            panic!("expected a property item list");
+        };
+        let rest = vec![
+            serde_json::from_value(json!({
+                "results": [
+                    {"object": "property_item", "id": "Upwn", "type": "relation", "relation": {"id": "28714e4a-5157-80cb-8961-000000000003"}}
+                ],
+                "next_cursor": null,
+                "has_more": false,
+                "type": "property_item",
+                "property_item": {"id": "Upwn", "next_url": null, "type": "relation", "relation": {}}
+            }))
+            .unwrap(),
+        ];
+
+        assert_eq!(
+            list.into_property_value(rest),
+            json!({
+                "id": "Upwn",
+                "type": "relation",
+                "relation": [
+                    {"id": "28714e4a-5157-80cb-8961-000000000001"},
+                    {"id": "28714e4a-5157-80cb-8961-000000000002"},
+                    {"id": "28714e4a-5157-80cb-8961-000000000003"}
+                ],
+                "has_more": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_rollup_item_list() {
+        let response: PropertyItemResponse = serde_json::from_value(json!({
+            "results": [
+                {"object": "property_item", "id": "r", "type": "relation", "relation": {"id": "28714e4a-5157-80cb-8961-000000000001"}},
+                {"object": "property_item", "id": "r", "type": "title", "title": {"type": "text", "text": {"content": "회의록", "link": null}, "plain_text": "회의록", "href": null}}
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": {"id": "r", "next_url": null, "type": "rollup",
+                "rollup": {"type": "array", "array": [], "function": "show_original"}}
+        }))
+        .unwrap();
+
+        let PropertyItemResponse::List(list) = response else {
+            panic!("expected a property item list");
+        };
+        let value: PropertyValue = list.into_property_value(vec![]).try_into().unwrap();
+        let PropertyValue::Rollup(rollup) = value else {
+            panic!("expected rollup");
+        };
+        let items = rollup.as_array().unwrap();
+
+        assert!(matches!(
+            &items[0],
+            PropertyValue::Relation(relation) if relation.get_pages().len() == 1
+        ));
+        assert_eq!(items[1].get_value(), "회의록");
+
+        // the aggregate of a paginated rollup is final on the last page only
+        let page = |cursor: Option<&str>, sum: f64| {
+            json!({
+                "results": [{"object": "property_item", "id": "r", "type": "number", "number": 1}],
+                "next_cursor": cursor,
+                "has_more": cursor.is_some(),
+                "type": "property_item",
+                "property_item": {"id": "r", "next_url": null, "type": "rollup",
+                    "rollup": {"type": "number", "number": sum, "function": "sum"}}
+            })
+        };
+        let PropertyItemResponse::List(list) =
+            serde_json::from_value(page(Some("c1"), 1.0)).unwrap()
+        else {
+            panic!("expected a property item list");
+        };
+        let rest = vec![
+            serde_json::from_value(page(Some("c2"), 2.0)).unwrap(),
+            serde_json::from_value(page(None, 3.0)).unwrap(),
+        ];
+        let value: PropertyValue = list.into_property_value(rest).try_into().unwrap();
+        assert_eq!(value.get_value(), "3");
+    }
+
+    #[test]
+    fn test_property_item() {
+        let response: PropertyItemResponse =
+            serde_json::from_value(json!({"id": "kjPO", "type": "number", "number": 2})).unwrap();
+
+        assert!(matches!(response, PropertyItemResponse::Item(_)));
+    }
+
+    #[test]
+    fn test_page_properties() {
+        let related = PageId::parse("28714e4a515780cb8961000b4d54c831").unwrap();
+        let user = UserId::parse("2d0d6924-cbad-43bd-87a5-cfcf7be2872b").unwrap();
+        let properties = PageProperties::new()
+            .title("Name", "회의록")
+            .number("점수", 3.0)
+            .number("점수", 4.0)
+            .multi_select("태그", ["컴퓨터", "집에서"])
+            .status("상태", "다음행동")
+            .people("담당자", &[user])
+            .relation("할일PJT", &[related])
+            .date(
+                "날짜",
+                DateValue::date(NaiveDate::from_ymd_opt(2025, 11, 27).unwrap()),
+            )
+            .to_value()
+            .unwrap();
+
+        assert_eq!(properties["Name"]["title"][0]["text"]["content"], "회의록");
+        assert_eq!(properties["점수"], json!({"number": 4.0}));
+        assert_eq!(
+            properties["태그"],
+            json!({"multi_select": [{"name": "컴퓨터"}, {"name": "집에서"}]})
+        );
+        assert_eq!(properties["상태"], json!({"status": {"name": "다음행동"}}));
+        assert_eq!(
+            properties["담당자"]["people"][0]["id"],
+            "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"
+        );
+        assert_eq!(
+            properties["할일PJT"],
+            json!({"relation": [{"id": "28714e4a-5157-80cb-8961-000b4d54c831"}]})
+        );
+        assert_eq!(
+            properties["날짜"],
+            json!({"date": {"start": "2025-11-27", "end": null, "time_zone": null}})
+        );
+
+        let raw: PageProperties = json!({"체크": {"checkbox": true}}).try_into().unwrap();
+        assert_eq!(
+            raw.set("URL", PropertyValue::Url(None)).to_value().unwrap(),
+            json!({"체크": {"checkbox": true}, "URL": {"url": null}})
+        );
+        for invalid in [json!([]), json!("체크"), Value::Null] {
+            assert!(matches!(
+                PageProperties::try_from(invalid),
+                Err(ClientError::ValidationError(_))
+            ));
+        }
+
+        let cleared = PageProperties::new()
+            .clear("날짜", "date")
+            .unwrap()
+            .clear("구분", "select")
+            .unwrap();
+        assert_eq!(
+            cleared.to_value().unwrap(),
+            json!({"날짜": {"date": null}, "구분": {"select": null}})
+        );
+        assert!(
+            PageProperties::new()
+                .clear("생성일", "created_time")
+                .is_err()
+        );
+
+        let read_only = PageProperties::new().set(
+            "생성일",
+            PropertyValue::CreatedTime("2025-11-07T00:00:00Z".parse().unwrap()),
+        );
+        assert!(matches!(
+            read_only.to_value(),
+            Err(ClientError::ReadOnlyPropertyError(_))
+        ));
+    }
+
+    #[test]
+    fn test_stale_pages() {
+        let verification = |state: &str, end: Option<&str>| {
+            page_fixture(json!({
+                "검증": {"id": "v", "type": "verification", "verification": {
+                    "state": state,
+                    "verified_by": {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"},
+                    "date": end.map(|end| json!({"start": "2025-11-01", "end": end}))
+                }}
+            }))
+        };
+        let pages = vec![
+            verification("verified", Some("2025-12-31")),
+            verification("verified", Some("2025-11-20")),
+            verification("expired", None),
+            verification("verified", None),
+            verification("unverified", None),
+            page_fixture(json!({})),
+        ];
+        let now = "2025-11-15T00:00:00Z".parse().unwrap();
+
+        let value = pages[1].get_verification().unwrap().unwrap();
+        assert_eq!(value.get_state(), VerificationState::Verified);
+        assert!(value.get_verified_by().is_some());
+        assert!(!value.is_expired(now));
+        assert!(value.is_expired("2025-11-20T00:00:00Z".parse().unwrap()));
+        assert!(pages[5].get_verification().unwrap().is_none());
+
+        let stale = find_stale_pages(&pages, now, Duration::days(7)).unwrap();
+        assert_eq!(stale.len(), 2);
+        assert!(std::ptr::eq(stale[0], &pages[1]));
+        assert!(std::ptr::eq(stale[1], &pages[2]));
+
+        let broken = vec![page_fixture(json!({
+            "검증": {"id": "v", "type": "verification", "verification": {"state": 1}}
+        }))];
+        assert!(matches!(
+            find_stale_pages(&broken, now, Duration::days(7)),
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+
+        let properties = PageProperties::new()
+            .verify(
+                "검증",
+                Some(DateValue::range(
+                    NaiveDate::from_ymd_opt(2025, 11, 15).unwrap(),
+                    NaiveDate::from_ymd_opt(2026, 2, 15).unwrap(),
+                )),
+            )
+            .to_value()
+            .unwrap();
+        assert_eq!(
+            properties["검증"],
+            json!({"verification": {"state": "verified", "date": {
+                "start": "2025-11-15", "end": "2026-02-15", "time_zone": null
+            }}})
+        );
+    }
+
+    #[test]
+    fn test_page_diff() {
+        let file = |url: &str| {
+            json!({"name": "a.pdf", "type": "file",
+            "file": {"url": url, "expiry_time": "2025-11-07T01:00:00.000Z"}})
+        };
+        let before = page_fixture(json!({
+            "상태": {"id": "s%3Dx", "type": "status", "status": {"name": "다음행동"}},
+            "첨부": {"id": "f", "type": "files", "files": [file("https://s3/a.pdf?sig=1")]},
+            "메모": {"id": "m", "type": "rich_text", "rich_text": []},
+        }));
+        let mut after = page_json(json!({
+            "상태": {"id": "s%3Dx", "type": "status", "status": {"name": "완료"}},
+            "첨부": {"id": "f", "type": "files", "files": [file("https://s3/a.pdf?sig=2")]},
+            "점수": {"id": "n", "type": "number", "number": 3},
+        }));
+        after["icon"] = json!({"type": "emoji", "emoji": "🚀"});
+        after["in_trash"] = json!(true);
+        after["parent"] =
+            json!({"type": "page_id", "page_id": "28714e4a-5157-80cb-8961-000000000001"});
+        let after: Page = serde_json::from_value(after).unwrap();
+
+        let diff = PageDiff::new(&before, &after).unwrap();
+        assert!(!diff.is_empty());
+        assert!(diff.get_property("첨부").is_none());
+        assert_eq!(diff.get_properties().len(), 3);
+
+        let status = diff.get_property("s=x").unwrap();
+        assert_eq!(status.get_kind(), ChangeKind::Changed);
+        assert!(matches!(
+            status.get_new(),
+            Some(PropertyValue::Status(Some(option))) if option.get_name() == "완료"
+        ));
+        assert_eq!(
+            diff.get_property("점수").unwrap().get_kind(),
+            ChangeKind::Added
+        );
+        assert_eq!(
+            diff.get_property("메모").unwrap().get_kind(),
+            ChangeKind::Removed
+        );
+        assert!(diff.get_archived().is_none());
+        assert!(*diff.get_in_trash().unwrap().get_new());
+        assert!(diff.get_cover().is_none());
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["properties"][0]["kind"], "changed");
+        assert_eq!(json["properties"][0]["new"]["status"]["name"], "완료");
+        assert_eq!(json["icon"]["new"]["emoji"], "🚀");
+        assert!(json.get("cover").is_none());
+
+        assert_eq!(
+            diff.to_string(),
+            [
+                "상태: 다음행동 → 완료",
+                "+ 점수: 3",
+                "- 메모: (empty)",
+                "Icon: (none) → 🚀",
+                "In trash: false → true",
+                "Parent: data_source_id 28714e4a-5157-8087-9cfd-000bf0979848 → page_id 28714e4a-5157-80cb-8961-000000000001",
+            ]
+            .join("\n")
+        );
+
+        assert!(PageDiff::new(&before, &before).unwrap().is_empty());
+    }
 }
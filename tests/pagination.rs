@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod test {
+    use notion::{
+        errors::ClientError,
+        page::types::Page,
+        pagination::{PaginatedList, PaginationCheckpoint},
+        types::NotionResponse,
+    };
+    use serde_json::{Value, json};
+
+    #[test]
+    fn test_paginated_list() {
+        let list: PaginatedList<Value> = serde_json::from_value(json!({
+            "results": [{"object": "user", "id": "1"}, {"object": "user", "id": "2"}],
+            "has_more": true,
+            "next_cursor": "fe2cc560-036c-44cd-90e8-294d5a74cebc",
+            "type": "user",
+            "user": {}
+        }))
+        .unwrap();
+
+        assert!(list.has_more());
+        assert_eq!(
+            list.get_next_cursor(),
+            Some("fe2cc560-036c-44cd-90e8-294d5a74cebc")
+        );
+        assert_eq!(list.into_results().len(), 2);
+    }
+
+    #[test]
+    fn test_paginated_list_last_page() {
+        let list: PaginatedList<Value> = serde_json::from_value(json!({
+            "results": [],
+            "has_more": false,
+            "next_cursor": null
+        }))
+        .unwrap();
+
+        assert!(!list.has_more());
+        assert_eq!(list.get_next_cursor(), None);
+    }
+
+    #[test]
+    fn test_invalid_results() {
+        let response: NotionResponse<PaginatedList<Page>> = serde_json::from_value(json!({
+            "object": "list",
+            "request_id": "c0a8b7e4-2f0c-4c4e-9e57-7e5e2d6f9a11",
+            "results": [{"object": "page", "id": "not-a-page-id"}],
+            "has_more": false,
+            "next_cursor": null
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            response.get_data(),
+            Err(ClientError::InvalidResponseError(_))
+        ));
+        assert!(matches!(
+            response.into_data(),
+            Err(ClientError::InvalidResponseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let endpoint = "data_sources/{data_source_id}/query?";
+        let body =
+            json!({"filter": {"property": "title", "title": {"is_not_empty": true}}, "sorts": []});
+
+        let saved = serde_json::to_string(&PaginationCheckpoint::new(endpoint, &body)).unwrap();
+        let checkpoint: PaginationCheckpoint = serde_json::from_str(&saved).unwrap();
+
+        assert_eq!(checkpoint.get_items_seen(), 0);
+        assert!(checkpoint.verify(endpoint, &body).is_ok());
+    }
+
+    #[test]
+    fn test_checkpoint_mismatch() {
+        let endpoint = "data_sources/{data_source_id}/query?";
+        let body =
+            json!({"filter": {"property": "title", "title": {"is_not_empty": true}}, "sorts": []});
+        let checkpoint = PaginationCheckpoint::new(endpoint, &body);
+
+        let changed =
+            json!({"filter": {"property": "title", "title": {"is_empty": true}}, "sorts": []});
+        assert!(matches!(
+            checkpoint.verify(endpoint, &changed),
+            Err(ClientError::CheckpointMismatchError(_))
+        ));
+        assert!(matches!(
+            checkpoint.verify("search", &body),
+            Err(ClientError::CheckpointMismatchError(_))
+        ));
+    }
+}
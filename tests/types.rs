@@ -0,0 +1,863 @@
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+    use chrono_tz::Tz;
+    use notion::{
+        data_source::types::PropertyInfo,
+        errors::ClientError,
+        ids::{PageId, UserId},
+        types::{
+            ClientResult, PropertyValue,
+            date::{DateTimeValue, DateValue},
+            display::{Locale, NumberFormat, Renderer},
+            file::{FileObject, FileSource},
+            formula::FormulaValue,
+            place::{BoundingBox, PlaceValue},
+            relation::{RELATION_WRITE_LIMIT, RelationValue},
+            rich_text::{Mention, RichText, RichTextContent, to_markdown, to_plain_text},
+            rollup::{RollupResult, RollupValue},
+            select::{Color, SelectOption},
+            unique_id::UniqueIdValue,
+            user::{BotOwner, User, UserType},
+        },
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_scalar_values() {
+        let number: PropertyValue = (&json!({"id": "kjPO", "type": "number", "number": 42.5}))
+            .try_into()
+            .unwrap();
+        assert_eq!(number, PropertyValue::Number(Some(42.5)));
+        assert_eq!(number.get_value(), "42.5");
+
+        let email: PropertyValue =
+            (&json!({"id": "a%3Db", "type": "email", "email": "dev@example.com"}))
+                .try_into()
+                .unwrap();
+        assert_eq!(email, PropertyValue::Email(Some("dev@example.com".into())));
+
+        let url: PropertyValue = (&json!({"id": "u", "type": "url", "url": null}))
+            .try_into()
+            .unwrap();
+        assert_eq!(url, PropertyValue::Url(None));
+
+        let rich_text: PropertyValue = (&json!({"id": "r", "type": "rich_text", "rich_text": [
+            {"type": "text", "text": {"content": "안녕 ", "link": null}, "plain_text": "안녕 ", "href": null},
+            {"type": "text", "text": {"content": "notion", "link": null}, "plain_text": "notion", "href": null}
+        ]})).try_into().unwrap();
+        assert_eq!(rich_text.get_value(), "안녕 notion");
+    }
+
+    #[test]
+    fn test_reference_values() {
+        let relation: PropertyValue = (&json!({"id": "Upwn", "type": "relation", "relation": [
+            {"id": "28714e4a-5157-80cb-8961-000b4d54c831"}
+        ], "has_more": false}))
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            relation,
+            PropertyValue::Relation(RelationValue::new(vec![
+                PageId::parse("28714e4a-5157-80cb-8961-000b4d54c831").unwrap()
+            ]))
+        );
+
+        let created_by: PropertyValue = (&json!({"id": "c", "type": "created_by", "created_by": {
+            "object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"
+        }}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::CreatedBy(user) = created_by else {
+            panic!("expected created_by");
+        };
+        assert_eq!(
+            user.get_id(),
+            &UserId::parse("2d0d6924-cbad-43bd-87a5-cfcf7be2872b").unwrap()
+        );
+
+        let edited: PropertyValue = (&json!({"id": "e", "type": "last_edited_time",
+            "last_edited_time": "2025-11-07T00:00:00.000Z"}))
+            .try_into()
+            .unwrap();
+        assert_eq!(edited.get_value(), "2025-11-07T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_rich_text_segments() {
+        let texts: Vec<RichText> = serde_json::from_value(json!([
+            {"type": "text", "text": {"content": "문서 ", "link": null},
+             "annotations": {"bold": true, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default"},
+             "plain_text": "문서 ", "href": null},
+            {"type": "mention", "mention": {"type": "page", "page": {"id": "28714e4a-5157-80cb-8961-000b4d54c831"}},
+             "annotations": {"bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default"},
+             "plain_text": "할 일", "href": "https://www.notion.so/28714e4a515780cb8961000b4d54c831"},
+            {"type": "equation", "equation": {"expression": "E = mc^2"},
+             "annotations": {"bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default"},
+             "plain_text": "E = mc^2", "href": null}
+        ]))
+        .unwrap();
+
+        assert!(matches!(
+            texts[1].get_content(),
+            RichTextContent::Mention {
+                mention: Mention::Page { .. }
+            }
+        ));
+        assert_eq!(to_plain_text(&texts), "문서 할 일E = mc^2");
+        assert_eq!(
+            to_markdown(&texts),
+            "**문서** [할 일](https://www.notion.so/28714e4a515780cb8961000b4d54c831)$E = mc^2$"
+        );
+    }
+
+    #[test]
+    fn test_unknown_rich_text() {
+        let title: PropertyValue = (&json!({"id": "title", "type": "title", "title": [
+            {"type": "text", "text": {"content": "see ", "link": null}, "plain_text": "see ", "href": null},
+            {"type": "mention", "mention": {"type": "link_mention", "link_mention": {"href": "https://github.com", "title": "GitHub"}},
+             "plain_text": "GitHub", "href": "https://github.com"},
+            {"type": "mention", "mention": {"type": "custom_emoji", "custom_emoji": {"id": "1", "name": "party", "url": "https://s3/party.png"}},
+             "plain_text": ":party:", "href": null},
+            {"type": "callout_text", "callout_text": {"content": "new"}, "plain_text": "!", "href": null}
+        ]}))
+            .try_into()
+            .unwrap();
+
+        assert_eq!(title.get_value(), "see GitHub:party:!");
+        let PropertyValue::Title(texts) = &title else {
+            panic!("expected title");
+        };
+        assert!(matches!(
+            texts[1].get_content(),
+            RichTextContent::Mention {
+                mention: Mention::Unknown(unknown)
+            } if unknown.get_type() == "link_mention"
+        ));
+
+        let written = title.to_write_value().unwrap();
+        assert_eq!(
+            written["title"][1]["mention"],
+            json!({"type": "link_mention", "link_mention": {"href": "https://github.com", "title": "GitHub"}})
+        );
+        assert_eq!(
+            written["title"][3]["callout_text"],
+            json!({"content": "new"})
+        );
+        assert_eq!(written["title"][3]["type"], "callout_text");
+
+        let malformed: Result<PropertyValue, _> =
+            (&json!({"id": "title", "type": "title", "title": [{"plain_text": "x"}]})).try_into();
+        assert!(matches!(
+            malformed,
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+    }
+
+    #[test]
+    fn test_rich_text_builder() {
+        let text = RichText::text("docs")
+            .bold()
+            .color("red")
+            .link("https://developers.notion.com")
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&text).unwrap(),
+            json!({
+                "type": "text",
+                "text": {"content": "docs", "link": {"url": "https://developers.notion.com"}},
+                "annotations": {"bold": true, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "red"}
+            })
+        );
+        assert_eq!(
+            text.to_markdown(),
+            "[**docs**](https://developers.notion.com)"
+        );
+
+        let page_id = PageId::parse("28714e4a-5157-80cb-8961-000000000001").unwrap();
+        assert!(matches!(
+            RichText::mention_page(&page_id).link("https://developers.notion.com"),
+            Err(ClientError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_date_values() {
+        let date: PropertyValue =
+            (&json!({"id": "d%5Dl%5D", "type": "date", "date": {"start": "2025-11-27", "end": null, "time_zone": null}})).try_into().unwrap();
+        let PropertyValue::Date(Some(date)) = date else {
+            panic!("expected date");
+        };
+        assert!(date.is_date_only());
+        assert_eq!(
+            date.get_start().date(),
+            NaiveDate::from_ymd_opt(2025, 11, 27).unwrap()
+        );
+
+        let range: DateValue = serde_json::from_value(json!({
+            "start": "2025-11-11T21:00:00.000+09:00",
+            "end": "2025-11-11T22:00:00.000+09:00",
+            "time_zone": null
+        }))
+        .unwrap();
+        assert!(!range.is_date_only());
+        assert_eq!(
+            range.to_string(),
+            "2025-11-11T21:00:00+09:00~2025-11-11T22:00:00+09:00"
+        );
+
+        let floating: DateValue = serde_json::from_value(
+            json!({"start": "2025-11-11T09:00:00", "time_zone": "Asia/Seoul"}),
+        )
+        .unwrap();
+        assert!(matches!(floating.get_start(), DateTimeValue::Floating(_)));
+        assert_eq!(floating.get_time_zone(), Some("Asia/Seoul"));
+    }
+
+    #[test]
+    fn test_write_date_value() {
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let start = kst.with_ymd_and_hms(2025, 11, 11, 21, 0, 0).unwrap();
+        let end = kst.with_ymd_and_hms(2025, 11, 11, 22, 0, 0).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(DateValue::range(start, end)).unwrap(),
+            json!({"start": "2025-11-11T21:00:00+09:00", "end": "2025-11-11T22:00:00+09:00", "time_zone": null})
+        );
+        assert_eq!(
+            serde_json::to_value(DateValue::date(
+                NaiveDate::from_ymd_opt(2025, 11, 27).unwrap()
+            ))
+            .unwrap(),
+            json!({"start": "2025-11-27", "end": null, "time_zone": null})
+        );
+        assert_eq!(
+            DateTimeValue::parse("2025-11-27")
+                .unwrap()
+                .to_datetime_in(&kst)
+                .unwrap()
+                .to_rfc3339(),
+            "2025-11-27T00:00:00+09:00"
+        );
+    }
+
+    #[test]
+    fn test_formula_values() {
+        let formula = |value| match PropertyValue::try_from(
+            &json!({"id": "f", "type": "formula", "formula": value}),
+        )
+        .unwrap()
+        {
+            PropertyValue::Formula(formula) => formula,
+            _ => panic!("expected formula"),
+        };
+
+        let minutes = formula(json!({"type": "number", "number": 90}));
+        assert_eq!(minutes.as_number(), Some(90.0));
+        assert_eq!(minutes.to_string(), "90");
+
+        assert_eq!(
+            formula(json!({"type": "boolean", "boolean": true})).as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            formula(json!({"type": "string", "string": "컴퓨터|집에서"})).as_str(),
+            Some("컴퓨터|집에서")
+        );
+
+        let date = formula(json!({"type": "date", "date": {"start": "2025-11-27", "end": null}}));
+        assert!(date.as_date().unwrap().is_date_only());
+
+        let empty = formula(json!({"type": "number", "number": null}));
+        assert!(empty.is_null());
+        assert_eq!(empty, FormulaValue::Number { number: None });
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn test_rollup_values() {
+        let rollup = |value| match PropertyValue::try_from(
+            &json!({"id": "r", "type": "rollup", "rollup": value}),
+        )
+        .unwrap()
+        {
+            PropertyValue::Rollup(rollup) => rollup,
+            _ => panic!("expected rollup"),
+        };
+
+        let count = rollup(json!({"type": "number", "number": 3, "function": "count"}));
+        assert_eq!(count.get_function(), "count");
+        assert_eq!(count.as_number(), Some(3.0));
+
+        let latest = rollup(
+            json!({"type": "date", "date": {"start": "2025-12-15"}, "function": "latest_date"}),
+        );
+        assert!(latest.as_date().is_some());
+
+        let original = rollup(
+            json!({"type": "array", "function": "show_original", "array": [
+                {"type": "status", "status": {"id": "1", "name": "진행 중", "color": "blue"}},
+                {"type": "status", "status": {"id": "2", "name": "완료", "color": "green"}}
+            ]}),
+        );
+        assert_eq!(original.as_array().unwrap().len(), 2);
+        assert_eq!(original.get_value(), "진행 중|완료");
+
+        let incomplete = rollup(json!({"type": "incomplete", "incomplete": {}, "function": "sum"}));
+        assert_eq!(incomplete.get_result(), &RollupResult::Incomplete);
+        assert!(!incomplete.is_complete());
+
+        let unsupported =
+            rollup(json!({"type": "unsupported", "unsupported": {}, "function": "show_original"}));
+        assert_eq!(
+            unsupported.get_result(),
+            &RollupResult::Unsupported("unsupported".into())
+        );
+
+        for malformed in [
+            json!({"type": "date", "date": {"start": "yesterday"}, "function": "latest_date"}),
+            json!({"type": "number", "number": "3", "function": "count"}),
+        ] {
+            assert!(matches!(
+                PropertyValue::try_from(&json!({"id": "r", "type": "rollup", "rollup": malformed})),
+                Err(ClientError::InvalidPropertyError(_))
+            ));
+        }
+
+        let empty: RollupValue = serde_json::from_value(
+            json!({"type": "date", "date": null, "function": "latest_date"}),
+        )
+        .unwrap();
+        assert_eq!(empty.get_result(), &RollupResult::Date(None));
+    }
+
+    #[test]
+    fn test_unknown_values() {
+        let raw = json!({"id": "b", "type": "button", "button": {}});
+        let button = PropertyValue::try_from(&raw).unwrap();
+        assert_eq!(
+            button,
+            PropertyValue::Unknown {
+                type_name: "button".into(),
+                raw: json!({}),
+            }
+        );
+        assert_eq!((&button).to_string(), "button");
+        // documented but read-only
+        assert!(!button.is_writable());
+        assert!(matches!(
+            button.to_write_value(),
+            Err(ClientError::ReadOnlyPropertyError(t)) if t == "button"
+        ));
+
+        let future =
+            PropertyValue::try_from(&json!({"id": "f", "type": "rating", "rating": 4})).unwrap();
+        assert!(future.is_writable());
+        assert_eq!(serde_json::to_value(&future).unwrap(), json!({"rating": 4}));
+
+        let malformed = PropertyValue::try_from(&json!({"id": "c", "checkbox": true}));
+        assert!(matches!(
+            malformed,
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+        let malformed =
+            PropertyValue::try_from(&json!({"id": "c", "type": "checkbox", "checkbox": "yes"}));
+        assert!(malformed.is_err());
+        let malformed =
+            PropertyValue::try_from(&json!({"id": "n", "type": "number", "number": "42"}));
+        assert!(matches!(
+            malformed,
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+    }
+
+    #[test]
+    fn test_write_values() {
+        let write = |value: PropertyValue| serde_json::to_value(value).unwrap();
+
+        assert_eq!(
+            write(PropertyValue::Title(vec![RichText::text("회의록")])),
+            json!({"title": [{
+                "type": "text",
+                "text": {"content": "회의록", "link": null},
+                "annotations": {"bold": false, "italic": false, "strikethrough": false,
+                    "underline": false, "code": false, "color": "default"}
+            }]})
+        );
+        assert_eq!(write(PropertyValue::Number(None)), json!({"number": null}));
+        assert_eq!(
+            write(PropertyValue::Select(Some("일정".into()))),
+            json!({"select": {"name": "일정"}})
+        );
+        assert_eq!(
+            write(PropertyValue::MultiSelect(vec![
+                "컴퓨터".into(),
+                "집에서".into()
+            ])),
+            json!({"multi_select": [{"name": "컴퓨터"}, {"name": "집에서"}]})
+        );
+        assert_eq!(
+            write(PropertyValue::Relation(
+                vec![PageId::parse("28714e4a515780cb8961000b4d54c831").unwrap()].into()
+            )),
+            json!({"relation": [{"id": "28714e4a-5157-80cb-8961-000b4d54c831"}]})
+        );
+        assert_eq!(
+            write(PropertyValue::People(vec![User::new(
+                &UserId::parse("2d0d6924-cbad-43bd-87a5-cfcf7be2872b").unwrap()
+            )])),
+            json!({"people": [{"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"}]})
+        );
+
+        let created: PropertyValue = (&json!({"id": "e", "type": "created_time",
+            "created_time": "2025-11-07T00:00:00.000Z"}))
+            .try_into()
+            .unwrap();
+        assert!(!created.is_writable());
+        assert!(matches!(
+            created.to_write_value(),
+            Err(ClientError::ReadOnlyPropertyError(t)) if t == "created_time"
+        ));
+        assert!(serde_json::to_value(&created).is_err());
+    }
+
+    #[test]
+    fn test_select_values() {
+        let status: PropertyValue = (&json!({"id": "MD%3AK", "type": "status", "status": {
+            "id": "1", "name": "진행 중", "color": "blue"
+        }}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::Status(Some(option)) = &status else {
+            panic!("expected status");
+        };
+        assert_eq!(option.get_color(), Color::Blue);
+        assert_eq!(status.get_value(), "진행 중");
+        // options read from Notion are written back by ID
+        assert_eq!(
+            serde_json::to_value(&status).unwrap(),
+            json!({"status": {"id": "1"}})
+        );
+
+        let tags: PropertyValue = (&json!({"id": "t", "type": "multi_select", "multi_select": [
+            {"id": "a", "name": "컴퓨터", "color": "green"},
+            {"id": "b", "name": "집에서", "color": "brown"}
+        ]}))
+            .try_into()
+            .unwrap();
+        assert_eq!(tags.get_value(), "컴퓨터|집에서");
+
+        let teal: PropertyValue = (&json!({"id": "s", "type": "select", "select": {
+            "id": "c", "name": "새 색", "color": "teal"
+        }}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::Select(Some(option)) = &teal else {
+            panic!("expected select");
+        };
+        assert_eq!(option.get_color(), Color::Unknown);
+        assert_eq!(
+            SelectOption::by_name("새 색")
+                .with_color(Color::Unknown)
+                .to_write_value(),
+            json!({"name": "새 색"})
+        );
+
+        assert_eq!(
+            serde_json::to_value(PropertyValue::Select(Some(
+                SelectOption::by_name("새 옵션").with_color(Color::Red)
+            )))
+            .unwrap(),
+            json!({"select": {"name": "새 옵션", "color": "red"}})
+        );
+        assert_eq!(
+            serde_json::to_value(PropertyValue::Select(Some(SelectOption::by_id("816b")))).unwrap(),
+            json!({"select": {"id": "816b"}})
+        );
+    }
+
+    #[test]
+    fn test_empty_values() {
+        let empty = [
+            json!({"id": "title", "type": "title", "title": []}),
+            json!({"id": "s", "type": "select", "select": null}),
+            json!({"id": "st", "type": "status", "status": null}),
+            json!({"id": "m", "type": "multi_select", "multi_select": []}),
+            json!({"id": "d", "type": "date", "date": null}),
+            json!({"id": "n", "type": "number", "number": null}),
+            json!({"id": "p", "type": "people", "people": []}),
+            json!({"id": "f", "type": "formula", "formula": {"type": "date", "date": null}}),
+            json!({"id": "r", "type": "rollup", "rollup": {"type": "date", "date": null, "function": "latest_date"}}),
+        ];
+        for value in &empty {
+            let property = PropertyValue::try_from(value).unwrap();
+            assert!(property.is_empty(), "{}", value);
+            assert_eq!(property.get_value(), "");
+        }
+
+        assert_eq!(
+            serde_json::to_value(PropertyValue::empty("select").unwrap()).unwrap(),
+            json!({"select": null})
+        );
+        assert_eq!(
+            serde_json::to_value(PropertyValue::empty("date").unwrap()).unwrap(),
+            json!({"date": null})
+        );
+        assert!(matches!(
+            PropertyValue::empty("formula"),
+            Err(ClientError::ReadOnlyPropertyError(_))
+        ));
+
+        // only unverified counts as empty, an expired verification still holds a value
+        assert!(PropertyValue::empty("verification").unwrap().is_empty());
+        let expired = PropertyValue::try_from(&json!({"id": "v", "type": "verification",
+            "verification": {"state": "expired", "verified_by": null, "date": null}}))
+        .unwrap();
+        assert!(!expired.is_empty());
+    }
+
+    #[test]
+    fn test_place_values() {
+        let visit: PropertyValue = (&json!({"id": "pl", "type": "place", "place": {
+            "lat": 37.5663, "lon": 126.9779, "name": "서울시청",
+            "address": "서울특별시 중구 세종대로 110", "google_place_id": "ChIJzWXFYYuifDUR64Pq5LTtioU"
+        }}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::Place(Some(city_hall)) = &visit else {
+            panic!("expected place");
+        };
+        assert_eq!(city_hall.get_name(), Some("서울시청"));
+        assert_eq!(visit.get_value(), "서울시청");
+        assert_eq!(
+            serde_json::to_value(&visit).unwrap()["place"]["lat"],
+            37.5663
+        );
+
+        let busan = PlaceValue::new(35.1796, 129.0756);
+        let distance = city_hall.distance_km(&busan);
+        assert!((320.0..330.0).contains(&distance), "{}", distance);
+
+        let gwanghwamun = PlaceValue::new(37.5759, 126.9768);
+        let around = BoundingBox::around(city_hall, 5.0);
+        assert!(around.contains(&gwanghwamun));
+        assert!(!around.contains(&busan));
+        assert!(gwanghwamun.is_within_km(city_hall, 5.0));
+
+        let date_line = BoundingBox::around(&PlaceValue::new(0.0, 179.9), 50.0);
+        assert!(date_line.contains(&PlaceValue::new(0.0, -179.9)));
+        assert!(!date_line.contains(&PlaceValue::new(0.0, 0.0)));
+
+        let empty: PropertyValue = (&json!({"id": "pl", "type": "place", "place": null}))
+            .try_into()
+            .unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_unique_id_values() {
+        let task: PropertyValue = (&json!({"id": "nd%3Fr", "type": "unique_id",
+            "unique_id": {"prefix": "TASK", "number": 42}}))
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            task,
+            PropertyValue::UniqueId(UniqueIdValue::new(Some("TASK"), 42))
+        );
+        assert_eq!(task.get_value(), "TASK-42");
+        assert!(!task.is_writable());
+
+        let plain: PropertyValue = (&json!({"id": "n", "type": "unique_id",
+            "unique_id": {"prefix": null, "number": 7}}))
+            .try_into()
+            .unwrap();
+        assert_eq!(plain.get_value(), "7");
+
+        let parsed: UniqueIdValue = "TASK-42".parse().unwrap();
+        assert_eq!(parsed.get_prefix(), Some("TASK"));
+        assert_eq!(parsed.get_number(), Some(42));
+        assert_eq!(
+            UniqueIdValue::parse("MY-TEAM-3").unwrap().get_prefix(),
+            Some("MY-TEAM")
+        );
+        assert_eq!(UniqueIdValue::parse("42").unwrap().get_prefix(), None);
+        assert!(UniqueIdValue::parse("TASK-").is_err());
+        assert!(UniqueIdValue::parse("-42").is_err());
+    }
+
+    #[test]
+    fn test_file_values() {
+        let files: PropertyValue = (&json!({"id": "f", "type": "files", "files": [
+            {"name": "회의록.pdf", "type": "file", "file": {
+                "url": "https://prod-files-secure.s3.us-west-2.amazonaws.com/a/회의록.pdf",
+                "expiry_time": "2025-11-07T01:00:00.000Z"
+            }},
+            {"name": "참고 링크", "type": "external", "external": {"url": "https://example.com/ref.png"}}
+        ]}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::Files(entries) = &files else {
+            panic!("expected files");
+        };
+        assert_eq!(files.get_value(), "회의록.pdf|참고 링크");
+        assert!(matches!(entries[0].get_source(), FileSource::File { .. }));
+        assert!(entries[0].is_expired("2025-11-07T02:00:00Z".parse().unwrap()));
+        assert!(!entries[1].is_expired("2025-11-07T02:00:00Z".parse().unwrap()));
+        assert_eq!(entries[1].get_url(), Some("https://example.com/ref.png"));
+
+        assert_eq!(
+            serde_json::to_value(PropertyValue::Files(vec![
+                FileObject::external("참고 링크", "https://example.com/ref.png"),
+                FileObject::file_upload("사진.jpg", "43833259-72ae-404e-8441-b6577f3159b4"),
+            ]))
+            .unwrap(),
+            json!({"files": [
+                {"name": "참고 링크", "type": "external", "external": {"url": "https://example.com/ref.png"}},
+                {"name": "사진.jpg", "type": "file_upload", "file_upload": {"id": "43833259-72ae-404e-8441-b6577f3159b4"}}
+            ]})
+        );
+        // expiry_time is read-only
+        assert_eq!(
+            serde_json::to_value(&entries[0]).unwrap()["file"],
+            json!({"url": "https://prod-files-secure.s3.us-west-2.amazonaws.com/a/회의록.pdf"})
+        );
+
+        // unknown sources are kept, malformed entries fail instead of being dropped
+        let raw = json!({"id": "f", "type": "files", "files": [
+            {"name": "drive.doc", "type": "google_drive", "google_drive": {"file_id": "1"}}
+        ]});
+        let files = PropertyValue::try_from(&raw).unwrap();
+        assert_eq!(
+            serde_json::to_value(&files).unwrap(),
+            json!({"files": [{"name": "drive.doc", "type": "google_drive", "google_drive": {"file_id": "1"}}]})
+        );
+        let malformed = json!({"id": "f", "type": "files", "files": [
+            {"name": "a.pdf", "type": "external", "external": {"url": "https://example.com/a.pdf"}},
+            {"name": "b.pdf"}
+        ]});
+        assert!(matches!(
+            PropertyValue::try_from(&malformed),
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+    }
+
+    #[test]
+    fn test_user_values() {
+        let people: PropertyValue = (&json!({"id": "p", "type": "people", "people": [
+            {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b", "type": "person",
+                "name": "김노션", "avatar_url": null, "person": {"email": "kim@example.com"}},
+            {"object": "user", "id": "9a05a5c2-e6f7-49c3-b2a8-2e5bed395799"}
+        ]}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::People(users) = &people else {
+            panic!("expected people");
+        };
+        assert!(users[0].is_person());
+        assert_eq!(users[0].get_email(), Some("kim@example.com"));
+        assert_eq!(users[1].get_type(), None);
+        assert_eq!(
+            people.get_value(),
+            "김노션|9a05a5c2-e6f7-49c3-b2a8-2e5bed395799"
+        );
+        assert_eq!(
+            serde_json::to_value(&people).unwrap(),
+            json!({"people": [
+                {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"},
+                {"object": "user", "id": "9a05a5c2-e6f7-49c3-b2a8-2e5bed395799"}
+            ]})
+        );
+
+        // other objects are written back as is, malformed entries fail instead of being dropped
+        let group = json!({"id": "p", "type": "people", "people": [
+            {"object": "group", "id": "1ded872b-594c-8101-9d0c-000285d4b0a3", "name": "디자인팀"},
+            {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b", "type": "agent"}
+        ]});
+        assert_eq!(
+            serde_json::to_value(PropertyValue::try_from(&group).unwrap()).unwrap(),
+            json!({"people": [
+                {"object": "group", "id": "1ded872b-594c-8101-9d0c-000285d4b0a3"},
+                {"object": "user", "id": "2d0d6924-cbad-43bd-87a5-cfcf7be2872b"}
+            ]})
+        );
+        let malformed = json!({"id": "p", "type": "people", "people": [{"object": "user"}]});
+        assert!(matches!(
+            PropertyValue::try_from(&malformed),
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+
+        let bot: PropertyValue = (&json!({"id": "c", "type": "last_edited_by", "last_edited_by": {
+            "object": "user", "id": "e8f65228-6110-4578-a56c-b2ef8330c27f", "type": "bot",
+            "name": "동기화", "bot": {"owner": {"type": "workspace", "workspace": true},
+                "workspace_name": "팀 공간"}
+        }}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::LastEditedBy(bot) = bot else {
+            panic!("expected last_edited_by");
+        };
+        assert_eq!(bot.get_type(), Some(UserType::Bot));
+        assert_eq!(bot.get_workspace_name(), Some("팀 공간"));
+        assert!(matches!(
+            bot.get_bot_owner(),
+            Some(BotOwner::Workspace { workspace: true })
+        ));
+    }
+
+    #[test]
+    fn test_relation_values() {
+        let page = |n: u32| PageId::parse(&format!("28714e4a-5157-80cb-8961-{:012}", n)).unwrap();
+        let relations = (0..25)
+            .map(|i| json!({"id": page(i).to_string()}))
+            .collect::<Vec<_>>();
+        let truncated: PropertyValue = (&json!({"id": "Upwn", "type": "relation",
+            "relation": relations, "has_more": true}))
+            .try_into()
+            .unwrap();
+        let PropertyValue::Relation(relation) = &truncated else {
+            panic!("expected relation");
+        };
+        assert_eq!(relation.get_pages().len(), 25);
+        assert!(relation.has_more());
+        assert!(matches!(
+            truncated.to_write_value(),
+            Err(ClientError::ValidationError(_))
+        ));
+
+        let malformed: ClientResult<PropertyValue> = (&json!({"id": "Upwn", "type": "relation",
+            "relation": [{"id": page(1).to_string()}, {"id": "not-a-page"}]}))
+            .try_into();
+        assert!(matches!(
+            malformed,
+            Err(ClientError::InvalidPropertyError(_))
+        ));
+
+        let mut relation = RelationValue::new(vec![page(1), page(2)]);
+        relation.add(&[page(2), page(3)]);
+        relation.remove(&[page(1)]);
+        assert_eq!(relation.get_pages(), &vec![page(2), page(3)]);
+        assert!(relation.contains(&page(3)));
+        assert_eq!(
+            PropertyValue::Relation(relation).to_write_value().unwrap(),
+            json!({"relation": [{"id": page(2)}, {"id": page(3)}]})
+        );
+
+        let mut relation = RelationValue::new((0..RELATION_WRITE_LIMIT as u32).map(page).collect());
+        assert!(
+            PropertyValue::Relation(relation.clone())
+                .to_write_value()
+                .is_ok()
+        );
+        relation.add(&[page(RELATION_WRITE_LIMIT as u32)]);
+        assert!(matches!(
+            PropertyValue::Relation(relation).to_write_value(),
+            Err(ClientError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_number_formats() {
+        assert_eq!(NumberFormat::parse("number").format(1234.5), "1234.5");
+        assert_eq!(
+            NumberFormat::parse("number_with_commas").format(-1234567.5),
+            "-1,234,567.5"
+        );
+        assert_eq!(NumberFormat::parse("percent").format(0.07), "7%");
+        assert_eq!(NumberFormat::parse("percent").format(12.5), "1,250%");
+        assert_eq!(NumberFormat::parse("won").format(1234567.4), "₩1,234,567");
+        assert_eq!(NumberFormat::parse("dollar").format(-5.0), "-$5.00");
+        assert_eq!(NumberFormat::parse("won").as_str(), "won");
+    }
+
+    #[test]
+    fn test_render_values() {
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let user_id = UserId::parse("1ded872b-594c-8101-9d0c-000285d4b0a3").unwrap();
+        let page_id = PageId::parse("28714e4a-5157-80cb-8961-000000000001").unwrap();
+        let other_id = PageId::parse("28714e4a-5157-80cb-8961-000000000002").unwrap();
+        let renderer = Renderer::new()
+            .with_offset(kst)
+            .with_locale(Locale::Ko)
+            .with_user_name(&user_id, "홍길동")
+            .with_page_title(&page_id, "회의록");
+
+        let won = PropertyInfo::Number {
+            format: NumberFormat::parse("won"),
+        };
+        assert_eq!(
+            renderer.render(&PropertyValue::Number(Some(15000.0)), Some(&won)),
+            "₩15,000"
+        );
+        assert_eq!(
+            renderer.render(&PropertyValue::Number(Some(15000.0)), None),
+            "15000"
+        );
+
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let date = DateValue::datetime(utc.with_ymd_and_hms(2025, 11, 27, 12, 30, 0).unwrap());
+        assert_eq!(
+            renderer.render(&PropertyValue::Date(Some(date.clone())), None),
+            "2025년 11월 27일 오후 9:30"
+        );
+        assert_eq!(
+            Renderer::new()
+                .with_locale(Locale::En)
+                .render(&PropertyValue::Date(Some(date)), None),
+            "Nov 27, 2025 12:30 PM"
+        );
+        let range = DateValue::range(
+            NaiveDate::from_ymd_opt(2025, 11, 27).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 11, 28).unwrap(),
+        );
+        assert_eq!(
+            renderer.render(&PropertyValue::Date(Some(range)), None),
+            "2025년 11월 27일 → 2025년 11월 28일"
+        );
+
+        assert_eq!(
+            renderer.render(&PropertyValue::People(vec![User::new(&user_id)]), None),
+            "홍길동"
+        );
+        assert_eq!(
+            renderer.render(
+                &PropertyValue::Relation(RelationValue::new(vec![page_id, other_id.clone()])),
+                None
+            ),
+            format!("회의록, {}", other_id)
+        );
+        assert_eq!(renderer.render(&PropertyValue::Checkbox(true), None), "예");
+    }
+
+    #[test]
+    fn test_render_time_zones() {
+        let new_york = Renderer::new().with_time_zone("America/New_York".parse::<Tz>().unwrap());
+        // daylight saving time in July, standard time in January
+        let summer = Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap();
+        let winter = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(new_york.render_timestamp(&summer), "2025-07-01 08:00");
+        assert_eq!(new_york.render_timestamp(&winter), "2025-01-01 07:00");
+
+        // floating values are local to their own time zone
+        let floating = NaiveDate::from_ymd_opt(2025, 11, 27)
+            .unwrap()
+            .and_hms_opt(21, 0, 0)
+            .unwrap();
+        let seoul = DateValue::new(DateTimeValue::Floating(floating)).with_time_zone("Asia/Seoul");
+        assert_eq!(Renderer::new().render_date(&seoul), "2025-11-27 12:00");
+        assert_eq!(new_york.render_date(&seoul), "2025-11-27 07:00");
+        assert_eq!(
+            Renderer::new().render_date(&DateValue::new(DateTimeValue::Floating(floating))),
+            "2025-11-27 21:00"
+        );
+    }
+
+    #[test]
+    fn test_get_value_fallback() {
+        assert_eq!(PropertyValue::Timestamp.get_value(), "");
+        let unknown: PropertyValue = (&json!({"id": "a", "type": "button", "button": {}}))
+            .try_into()
+            .unwrap();
+        assert_eq!(unknown.get_value(), "{}");
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test {
+    use notion::{
+        errors::ClientError,
+        ids::{BlockId, DataSourceId, DatabaseId, PageId, ViewId},
+        url::NotionUrl,
+    };
+
+    #[test]
+    fn test_page_url() {
+        let url = NotionUrl::parse(
+            "https://www.notion.so/workspace/Title-28714e4a515780cb8961000b4d54c831",
+        )
+        .unwrap();
+
+        assert_eq!(
+            url,
+            NotionUrl::Page(PageId::parse("28714e4a515780cb8961000b4d54c831").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_database_url() {
+        let url = NotionUrl::parse(
+            "https://www.notion.so/workspace/28714e4a515780d38986d613367fa5a1?v=28714e4a515780a084bf000b81cda798",
+        )
+        .unwrap();
+
+        assert_eq!(
+            url,
+            NotionUrl::Database {
+                database_id: DatabaseId::parse("28714e4a515780d38986d613367fa5a1").unwrap(),
+                view_id: Some(ViewId::parse("28714e4a515780a084bf000b81cda798").unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_peek_and_block_url() {
+        let peek = NotionUrl::parse(
+            "https://team.notion.site/28714e4a515780d38986d613367fa5a1?v=28714e4a515780a084bf000b81cda798&p=28714e4a515780cb8961000b4d54c831&pm=s",
+        )
+        .unwrap();
+        assert_eq!(
+            peek.get_page_id(),
+            Some(&PageId::parse("28714e4a515780cb8961000b4d54c831").unwrap())
+        );
+        assert_eq!(
+            peek.get_database_id(),
+            Some(&DatabaseId::parse("28714e4a515780d38986d613367fa5a1").unwrap())
+        );
+
+        let block = NotionUrl::parse(
+            "https://www.notion.so/Title-28714e4a515780cb8961000b4d54c831#2d0d6924cbad43bd87a5cfcf7be2872b",
+        )
+        .unwrap();
+        assert_eq!(
+            block,
+            NotionUrl::Block {
+                page_id: PageId::parse("28714e4a515780cb8961000b4d54c831").unwrap(),
+                block_id: BlockId::parse("2d0d6924cbad43bd87a5cfcf7be2872b").unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_data_source_url() {
+        let url = NotionUrl::parse("collection://28714e4a-5157-8087-9cfd-000bf0979848").unwrap();
+
+        assert_eq!(
+            url,
+            NotionUrl::DataSource(
+                DataSourceId::parse("28714e4a-5157-8087-9cfd-000bf0979848").unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_custom_domain_url() {
+        let url = NotionUrl::parse(
+            "https://docs.example.com/guides/Title-28714e4a515780cb8961000b4d54c831#2d0d6924cbad43bd87a5cfcf7be2872b",
+        )
+        .unwrap();
+
+        assert_eq!(
+            url.get_page_id(),
+            Some(&PageId::parse("28714e4a515780cb8961000b4d54c831").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        for value in [
+            "https://example.com/about",
+            "https://example.com/build-28714e4a515780cb8961000b4d54c83",
+            "ftp://www.notion.so/Title-28714e4a515780cb8961000b4d54c831",
+            "https://www.notion.so/workspace/Title",
+            "28714e4a515780cb8961000b4d54c831",
+        ] {
+            assert!(matches!(
+                NotionUrl::parse(value),
+                Err(ClientError::InvalidUrlError(_))
+            ));
+        }
+    }
+}